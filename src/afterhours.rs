@@ -0,0 +1,51 @@
+//! Classifies commit timestamps as inside or outside a configurable weekday
+//! work window, for the playful "After-hours coder" stat.
+
+use chrono::{DateTime, Datelike, Timelike, Utc, Weekday};
+use serde::Deserialize;
+
+/// The weekday work window commits are compared against. Weekend commits
+/// are always after-hours regardless of the hour.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AfterHoursConfig {
+    #[serde(default = "default_work_start_hour")]
+    pub work_start_hour: u32,
+    #[serde(default = "default_work_end_hour")]
+    pub work_end_hour: u32,
+}
+
+fn default_work_start_hour() -> u32 {
+    9
+}
+
+fn default_work_end_hour() -> u32 {
+    17
+}
+
+impl Default for AfterHoursConfig {
+    fn default() -> Self {
+        Self { work_start_hour: default_work_start_hour(), work_end_hour: default_work_end_hour() }
+    }
+}
+
+/// Whether `commit`, bucketed via `utc_offset_hours` (see
+/// [`crate::config::Config::utc_offset_hours`]), falls outside `config`'s
+/// weekday work window.
+fn is_after_hours(commit: DateTime<Utc>, utc_offset_hours: i32, config: &AfterHoursConfig) -> bool {
+    let local = commit + chrono::Duration::hours(utc_offset_hours as i64);
+    if matches!(local.weekday(), Weekday::Sat | Weekday::Sun) {
+        return true;
+    }
+    let hour = local.hour();
+    hour < config.work_start_hour || hour >= config.work_end_hour
+}
+
+/// Share (0.0-1.0) of `commits` classified as after-hours. `None` if
+/// `commits` is empty.
+pub fn after_hours_share(commits: &[DateTime<Utc>], utc_offset_hours: i32, config: &AfterHoursConfig) -> Option<f64> {
+    if commits.is_empty() {
+        return None;
+    }
+    let after_hours = commits.iter().filter(|&&c| is_after_hours(c, utc_offset_hours, config)).count();
+    Some(after_hours as f64 / commits.len() as f64)
+}
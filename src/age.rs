@@ -0,0 +1,38 @@
+//! Age calculation for the profile card's "uptime" row.
+
+use chrono::{Datelike, NaiveDate, Utc};
+
+/// Computes age as `"{years} years, {months} months, {days} days"` relative to today.
+pub fn calculate_age(birthday: NaiveDate) -> String {
+    let today = Utc::now().date_naive();
+    let mut years = today.year() - birthday.year();
+    let mut months = today.month() as i32 - birthday.month() as i32;
+    let mut days = today.day() as i32 - birthday.day() as i32;
+
+    if days < 0 {
+        months -= 1;
+        let (prev_year, prev_month) = if today.month() == 1 {
+            (today.year() - 1, 12)
+        } else {
+            (today.year(), today.month() - 1)
+        };
+        days += days_in_month(prev_year, prev_month) as i32;
+    }
+    if months < 0 {
+        years -= 1;
+        months += 12;
+    }
+
+    format!("{years} years, {months} months, {days} days")
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let first = NaiveDate::from_ymd_opt(year, month, 1).expect("valid calendar date");
+    let next = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    }
+    .expect("valid calendar date");
+    (next - first).num_days() as u32
+}
@@ -0,0 +1,91 @@
+//! The ASCII art rendered in the card's left column: [`DEFAULT_ASCII_ART`] is
+//! the built-in default, overridable with a user's own art loaded from a
+//! text file — see `Config::ascii_art_file`/`--ascii-art-file`. Every sizing
+//! function here takes the art as a `&str` rather than assuming the
+//! constant, so a custom file's width/height are computed the same way the
+//! default's always have been.
+
+pub const DEFAULT_ASCII_ART: &str = r#"
+     _.-'''''-._
+   .'   _   _   '.
+  /    (o)_(o)    \
+ |     .-'''-.     |
+ |    /       \    |
+  \  '.       .'  /
+   '.  '-...-'  .'
+     '-._____.-'
+"#;
+
+const LINE_HEIGHT: i32 = 18;
+const CHAR_WIDTH: u32 = 10;
+/// Number of spaces a `\t` expands to before width/escape handling runs.
+pub const TAB_WIDTH: usize = 4;
+
+/// Expands tabs to `TAB_WIDTH` spaces, tracking column position so tabs that
+/// land mid-line still snap to the next tab stop instead of a flat expansion.
+pub fn expand_tabs(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut col = 0usize;
+    for ch in line.chars() {
+        if ch == '\t' {
+            let spaces = TAB_WIDTH - (col % TAB_WIDTH);
+            out.extend(std::iter::repeat(' ').take(spaces));
+            col += spaces;
+        } else {
+            out.push(ch);
+            col += 1;
+        }
+    }
+    out
+}
+
+/// Checks `art` for characters that would come out wrong or break the SVG
+/// output, returning one warning per offending line. [`build_ascii_tspans`]
+/// already escapes `&`/`<`/`>` for well-formed XML, so this only flags what
+/// escaping can't fix: control characters (other than `\n`/`\t`), which
+/// SVG's XML parser rejects outright.
+pub fn validate(art: &str) -> Vec<String> {
+    art.lines()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let bad: String = line.chars().filter(|c| c.is_control() && *c != '\t').collect();
+            if bad.is_empty() {
+                None
+            } else {
+                Some(format!("line {}: contains control character(s) {bad:?}, which SVG can't embed as text", i + 1))
+            }
+        })
+        .collect()
+}
+
+/// Escapes `&`/`<`/`>` so `s` can't break out of an SVG `<text>`/`<tspan>`
+/// node or, worse, inject markup of its own — used everywhere a stat pulled
+/// from an external command, a GitHub API response, or other free text ends
+/// up interpolated straight into the SVG.
+pub fn escape_xml_text(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+/// Splits `art` into positioned `<tspan>` elements for the left column.
+pub fn build_ascii_tspans(art: &str, x: i32, y: i32) -> Vec<String> {
+    art.trim_matches('\n')
+        .lines()
+        .map(expand_tabs)
+        .enumerate()
+        .map(|(i, line)| format!(r#"<tspan x="{x}" y="{}">{}</tspan>"#, y + i as i32 * LINE_HEIGHT, escape_xml_text(&line)))
+        .collect()
+}
+
+/// Width in characters of `art`'s widest line (after tab expansion), used to
+/// size the left column.
+pub fn ascii_chars_wide(art: &str) -> usize {
+    art.trim_matches('\n').lines().map(|line| expand_tabs(line).chars().count()).max().unwrap_or(0)
+}
+
+pub fn ascii_width_px(art: &str) -> u32 {
+    ascii_chars_wide(art) as u32 * CHAR_WIDTH
+}
+
+pub fn ascii_line_count(art: &str) -> usize {
+    art.trim_matches('\n').lines().count()
+}
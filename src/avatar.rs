@@ -0,0 +1,31 @@
+//! The left column's optional GitHub-avatar alternative to
+//! [`crate::ascii::DEFAULT_ASCII_ART`] — [`fetch_base64`] downloads and
+//! inlines the user's avatar (see
+//! `GithubClient::avatar_url`/`Config::show_avatar`/`--show-avatar`) so the
+//! rendered card is self-contained instead of hotlinking `avatars.githubusercontent.com`.
+//! [`build_avatar_block`] is [`crate::ascii::build_ascii_tspans`]'s sibling
+//! for this slot: an inline `<image>` instead of a block of `<tspan>` text.
+
+use crate::error::Result;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+
+/// Rendered width and height of the avatar block, in pixels — square, like
+/// the source image GitHub serves.
+pub const AVATAR_SIZE_PX: u32 = 120;
+
+/// Downloads the image at `url` and returns it as a base64 data URI, guessing
+/// the MIME type from the response's `Content-Type` header and falling back
+/// to `image/png` (what GitHub serves default identicons as) if it's absent.
+pub fn fetch_base64(url: &str) -> Result<String> {
+    let response = reqwest::blocking::get(url)?.error_for_status()?;
+    let content_type = response.headers().get(reqwest::header::CONTENT_TYPE).and_then(|v| v.to_str().ok()).unwrap_or("image/png").to_string();
+    let bytes = response.bytes()?;
+    Ok(format!("data:{content_type};base64,{}", BASE64.encode(bytes)))
+}
+
+/// Renders `data_uri` (see [`fetch_base64`]) as an inline `<image>` at
+/// `(x, y)`, [`AVATAR_SIZE_PX`] square.
+pub fn build_avatar_block(data_uri: &str, x: i32, y: i32) -> String {
+    format!(r#"<image x="{x}" y="{y}" width="{AVATAR_SIZE_PX}" height="{AVATAR_SIZE_PX}" href="{data_uri}"/>"#)
+}
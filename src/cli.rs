@@ -0,0 +1,93 @@
+//! A `clap::Command` describing halfguru's subcommands and flags, used only
+//! to drive `completions`/`man` generation for now — day-to-day argument
+//! parsing is still the manual `flag_value`/`flag_present` helpers in
+//! `main.rs` until the full clap migration.
+//!
+//! Keep this in sync with `main.rs`'s flags by hand until that migration
+//! lands and this becomes the single source of truth.
+
+use clap::{Arg, Command};
+
+pub fn command() -> Command {
+    Command::new("halfguru")
+        .about("Generates a GitHub profile stats card as SVG")
+        .arg(Arg::new("username").long("username").action(clap::ArgAction::Append).help("Render a third-person card for this login (repeatable)"))
+        .arg(Arg::new("birthday").long("birthday").help("Birthday (YYYY-MM-DD) for the \"Uptime\" row, in place of the hardcoded default"))
+        .arg(Arg::new("output-dir").long("output-dir").help("Directory to write rendered card(s) to (default: dist)"))
+        .arg(Arg::new("theme").long("theme").help("\"dark\", \"dracula\", \"gruvbox\", \"catppuccin\", or \"solarized\"; anything else keeps the default"))
+        .arg(Arg::new("theme-file").long("theme-file").help("Path to a JSON theme file (hex colors for bg/text/key/value/cc/add/del), overriding --theme"))
+        .arg(Arg::new("ascii-art-file").long("ascii-art-file").help("Path to a text file replacing the default left-column ASCII art"))
+        .arg(Arg::new("show-avatar").long("show-avatar").action(clap::ArgAction::SetTrue).help("Show the user's GitHub avatar in the left column instead of ASCII art"))
+        .arg(Arg::new("skip-loc").long("skip-loc").action(clap::ArgAction::SetTrue).help("Skip the \"Lines of code\" collection for a faster render"))
+        .arg(Arg::new("gist").long("gist").action(clap::ArgAction::SetTrue).help("Upload the rendered card to a gist"))
+        .arg(Arg::new("gist-id").long("gist-id").help("Existing gist ID to update instead of creating a new one"))
+        .arg(Arg::new("commit-to").long("commit-to").help("Push the rendered card to OWNER/REPO via the contents API"))
+        .arg(Arg::new("commit-branch").long("commit-branch").help("Branch to commit to with --commit-to (default: assets)"))
+        .arg(Arg::new("dump-model").long("dump-model").action(clap::ArgAction::SetTrue).help("Print the render model as JSON instead of writing SVG"))
+        .arg(Arg::new("show-collaborators").long("show-collaborators").action(clap::ArgAction::SetTrue).help("Add a \"Frequent collaborators\" row from commit co-author trailers"))
+        .arg(Arg::new("show-star-history").long("show-star-history").action(clap::ArgAction::SetTrue).help("Add a \"Star history\" chart from the top repos' stargazer timestamps"))
+        .arg(Arg::new("show-spotlight").long("show-spotlight").action(clap::ArgAction::SetTrue).help("Add a \"Spotlight\" box highlighting the user's most-starred repo"))
+        .arg(Arg::new("show-contribution-history").long("show-contribution-history").action(clap::ArgAction::SetTrue).help("Add a \"Contribution history\" table of per-year totals back to account creation"))
+        .arg(Arg::new("show-top-languages").long("show-top-languages").action(clap::ArgAction::SetTrue).help("Add a \"Top Languages\" legend from per-language byte counts across owned repos"))
+        .arg(Arg::new("show-streak").long("show-streak").action(clap::ArgAction::SetTrue).help("Add \"Current streak\"/\"Longest streak\" rows from the contribution calendar"))
+        .arg(Arg::new("show-commits-all-time").long("show-commits-all-time").action(clap::ArgAction::SetTrue).help("Add an \"All-time commits\" row summed across every year since account creation"))
+        .arg(Arg::new("after-hours").long("after-hours").action(clap::ArgAction::SetTrue).help("Add an \"After-hours coder\" row from commit timestamps"))
+        .arg(Arg::new("dual-theme").long("dual-theme").action(clap::ArgAction::SetTrue).help("Write separate light/dark SVGs plus a <picture> snippet instead of one theme-neutral file"))
+        .arg(Arg::new("picture-out").long("picture-out").help("Write the --dual-theme <picture> snippet here instead of stdout"))
+        .arg(Arg::new("debug-dump").long("debug-dump").action(clap::ArgAction::SetTrue).help("Write raw API responses, stats, render model, and environment info to a zip for bug reports"))
+        .arg(Arg::new("debug-dump-out").long("debug-dump-out").help("Path for the --debug-dump zip (default: halfguru-debug.zip)"))
+        .arg(Arg::new("quotes-file").long("quotes-file").help("One quote per line; adds a \"Quote\" row picked from this list"))
+        .arg(Arg::new("quote-daily-seed").long("quote-daily-seed").action(clap::ArgAction::SetTrue).help("Pick the same quote all day instead of a new one every render"))
+        .arg(Arg::new("weather-lat").long("weather-lat").help("Latitude for the \"Weather\" row (requires --weather-lon)"))
+        .arg(Arg::new("weather-lon").long("weather-lon").help("Longitude for the \"Weather\" row (requires --weather-lat)"))
+        .arg(Arg::new("status-message").long("status-message").help("Adds a \"Status\" row with this message while today falls in the --status-from/--status-until range"))
+        .arg(Arg::new("status-from").long("status-from").help("Start date (YYYY-MM-DD) for --status-message; open-ended if omitted"))
+        .arg(Arg::new("status-until").long("status-until").help("End date (YYYY-MM-DD) for --status-message; open-ended if omitted"))
+        .arg(Arg::new("check").long("check").action(clap::ArgAction::SetTrue).help("Exit 2 if the regenerated card would differ from what's on disk"))
+        .arg(Arg::new("dev-cache").long("dev-cache").action(clap::ArgAction::SetTrue).help("Cache raw GraphQL responses on disk for local development"))
+        .arg(Arg::new("cache-dir").long("cache-dir").help("Override the platform default cache directory"))
+        .arg(Arg::new("otlp-endpoint").long("otlp-endpoint").help("Export tracing spans to this OTLP collector"))
+        .arg(Arg::new("error-format").long("error-format").help("Set to \"json\" to print failures as a single JSON object instead of plain text"))
+        .arg(Arg::new("format").long("format").help("Comma-separated output formats to write: svg (default), png, csv (appends a row to a history CSV instead of overwriting), html (self-contained light/dark iframe widget)"))
+        .arg(Arg::new("metrics-push-url").long("metrics-push-url").help("Push this run's stats here as a time-series point, e.g. an InfluxDB write endpoint or Prometheus Pushgateway URL"))
+        .arg(Arg::new("metrics-push-format").long("metrics-push-format").help("\"prometheus\" for Pushgateway exposition format; anything else (default) for InfluxDB line protocol"))
+        .arg(Arg::new("webhook-url").long("webhook-url").help("Post a run summary to this Discord or Slack incoming webhook URL after each render"))
+        .arg(Arg::new("notify-telegram").long("notify-telegram").action(clap::ArgAction::SetTrue).help("Post a run summary to Telegram via TELEGRAM_BOT_TOKEN/TELEGRAM_CHAT_ID (see secrets)"))
+        .arg(Arg::new("webhook-include-image").long("webhook-include-image").action(clap::ArgAction::SetTrue).help("Attach the rendered card as a PNG to the webhook/Telegram notification (Discord and Telegram only)"))
+        .subcommand(Command::new("leaderboard").about("Renders a multi-user commit leaderboard card"))
+        .subcommand(
+            Command::new("verify")
+                .about("Recomputes stats with a second independent method and reports any discrepancies")
+                .arg(Arg::new("username").long("username").help("Login to verify (default: halfguru)")),
+        )
+        .subcommand(
+            Command::new("repo-card")
+                .about("Renders a single-repository stats card")
+                .arg(Arg::new("owner").long("owner").help("Repository owner (default: halfguru)"))
+                .arg(Arg::new("repo").long("repo").required(true).help("Repository name")),
+        )
+        .subcommand(
+            Command::new("server")
+                .about("Runs as a long-lived daemon: re-renders on a timer, hot-reloads config, and serves cards over HTTP")
+                .arg(Arg::new("config").long("config").help("Config file path (default: platform config directory)"))
+                .arg(Arg::new("health-addr").long("health-addr").help("Address for /healthz, /readyz, and /card (default: 0.0.0.0:8080)"))
+                .arg(Arg::new("health-token").long("health-token").help("Require this as ?token= on every request"))
+                .arg(Arg::new("health-rate-limit").long("health-rate-limit").help("Requests allowed per source IP per minute (default: 60)")),
+        )
+        .subcommand(Command::new("self-update").about("Downloads and installs the latest release, replacing the running binary"))
+        .subcommand(
+            Command::new("inject")
+                .about("Splices an already-rendered SVG into a README between halfguru:start/halfguru:end markers")
+                .arg(Arg::new("readme").long("readme").help("File to update (default: README.md)"))
+                .arg(Arg::new("svg").long("svg").help("Rendered SVG to inject (default: <output-dir>/card.svg)"))
+                .arg(Arg::new("alt").long("alt").help("Alt text for the <img> tag (default: \"GitHub stats card\")"))
+                .arg(Arg::new("inline").long("inline").action(clap::ArgAction::SetTrue).help("Inline the raw SVG markup instead of an <img> tag")),
+        )
+        .subcommand(
+            Command::new("completions")
+                .about("Prints a shell completion script")
+                .arg(Arg::new("shell").required(true).help("bash, zsh, fish, elvish, or powershell")),
+        )
+        .subcommand(Command::new("man").about("Prints a man page"))
+        .subcommand(Command::new("schema").about("Prints a JSON Schema for the config file, for editor validation/autocomplete"))
+}
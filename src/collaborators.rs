@@ -0,0 +1,37 @@
+//! Extracts co-author handles from commit trailers, for the opt-in
+//! "Frequent collaborators" row (see [`crate::render::build_model`]). Opt-in
+//! because it surfaces other people's handles on the card owner's stats,
+//! which isn't something halfguru should do by default.
+
+/// Parses `Co-authored-by: Name <email>` trailers out of a commit message,
+/// returning the GitHub login guessed from each email. GitHub's noreply
+/// addresses embed the login as `id+login@users.noreply.github.com`; any
+/// other address falls back to its local part, which is usually the login
+/// but isn't guaranteed to be.
+pub fn extract_co_authors(message: &str) -> Vec<String> {
+    message
+        .lines()
+        .filter_map(|line| line.split_once("Co-authored-by:").map(|(_, rest)| rest))
+        .filter_map(|rest| {
+            let email = rest.trim().rsplit_once('<')?.1.trim_end_matches('>').to_string();
+            let local = email.split('@').next()?.to_string();
+            Some(local.rsplit_once('+').map(|(_, login)| login.to_string()).unwrap_or(local))
+        })
+        .filter(|login| !login.is_empty())
+        .collect()
+}
+
+/// Ranks logins by how often they appear, most frequent first, keeping only
+/// the top `limit` and dropping `exclude` (the card owner, who co-authoring
+/// their own commits shouldn't count as a collaborator).
+pub fn top_collaborators(logins: impl IntoIterator<Item = String>, exclude: &str, limit: usize) -> Vec<String> {
+    let mut counts = std::collections::HashMap::new();
+    for login in logins {
+        if login != exclude {
+            *counts.entry(login).or_insert(0u32) += 1;
+        }
+    }
+    let mut ranked: Vec<(String, u32)> = counts.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked.into_iter().take(limit).map(|(login, _)| login).collect()
+}
@@ -0,0 +1,292 @@
+//! On-disk configuration for long-running server/daemon modes (see
+//! `server.rs`). One-shot CLI runs still use the hardcoded constants and
+//! flags in `main.rs`.
+
+use crate::afterhours::AfterHoursConfig;
+use crate::custom_section::CustomCommandConfig;
+use crate::error::Result;
+use crate::postprocess::FormulaStat;
+use crate::quote::QuoteConfig;
+use crate::skills::SkillEntry;
+use crate::status::StatusEntry;
+use crate::streak::StreakConfig;
+use crate::timeline::TimelineEntry;
+use crate::weather::WeatherConfig;
+use crate::svg::{CustomThemeConfig, Locale, Palette, PrivateContributionsMode, StatLimits, Theme, ThemeColors};
+use serde::Deserialize;
+use std::path::Path;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Config {
+    #[serde(default = "default_theme")]
+    pub theme: String,
+    /// A fully custom theme, overriding whatever [`Self::theme`] picked.
+    /// `None` (the default) leaves the named theme in charge. See
+    /// [`CustomThemeConfig`].
+    #[serde(default)]
+    pub custom_theme: Option<CustomThemeConfig>,
+    /// Path to a text file with the left-column ASCII art, replacing
+    /// [`crate::ascii::DEFAULT_ASCII_ART`]. `None` (the default) keeps the
+    /// built-in art.
+    #[serde(default)]
+    pub ascii_art_file: Option<String>,
+    /// Show the user's GitHub avatar in the left column instead of ASCII
+    /// art. Off by default since it costs an extra query plus an image
+    /// download per render. See [`crate::avatar`].
+    #[serde(default)]
+    pub show_avatar: bool,
+    /// Color-blind-friendly override for the add/del (and future heatmap)
+    /// colors: `"standard"` (default), `"deuteranopia"`, or `"protanopia"`.
+    /// See [`Palette`].
+    #[serde(default)]
+    pub palette: String,
+    /// How the "Contribution mix" legend handles private-repo commits:
+    /// `"hidden"` (default, matches GitHub's own profile behavior), `"fold"`
+    /// (add them into the public commit count), or `"show"` (display them
+    /// alongside it as `"(+N private)"`). See [`crate::svg::PrivateContributionsMode`].
+    #[serde(default)]
+    pub private_contributions: String,
+    #[serde(default)]
+    pub excluded_repos: Vec<String>,
+    /// Usernames the on-demand `/card` server endpoint will render besides
+    /// the owner. Empty by default, so a freshly deployed server only ever
+    /// serves the owner's own card instead of becoming a free stats API for
+    /// whoever finds the URL.
+    #[serde(default)]
+    pub allowed_users: Vec<String>,
+    /// Grace rules for the commit streak stat. See [`StreakConfig`].
+    #[serde(default)]
+    pub streak: StreakConfig,
+    /// Hours added to UTC before bucketing any date-based stat (streak
+    /// boundaries, weekday breakdown, contribution cadence) into a calendar
+    /// day. GitHub's contribution calendar is already bucketed in the
+    /// profile's own timezone, so recomputing day boundaries in UTC locally
+    /// risks an off-by-one day against what the calendar shows — this keeps
+    /// every date-bucketed stat agreeing with it, and with each other.
+    #[serde(default)]
+    pub utc_offset_hours: i32,
+    /// Whether to walk commit history for co-author handles and show the
+    /// "Frequent collaborators" row. Off by default — see
+    /// [`crate::collaborators`].
+    #[serde(default)]
+    pub show_collaborators: bool,
+    /// Whether to page stargazer history for the top starred repos and show
+    /// the "Star history" chart. Off by default since it's the most
+    /// expensive collector this crate runs — see [`crate::star_history`].
+    #[serde(default)]
+    pub show_star_history: bool,
+    /// Whether to fetch the user's most-starred repo and show the
+    /// "Spotlight" box. Off by default since it's an extra API round trip
+    /// most cards don't need — see [`crate::spotlight`].
+    #[serde(default)]
+    pub show_spotlight: bool,
+    /// Whether to query per-year contribution totals back to account
+    /// creation and show the "Contribution history" table. Off by default
+    /// since it costs one query per year — see
+    /// [`crate::contribution_history`].
+    #[serde(default)]
+    pub show_contribution_history: bool,
+    /// Whether to sum per-language byte counts across owned, non-fork repos
+    /// and show the "Top Languages" section. Off by default since it costs
+    /// an extra paged query — see [`crate::top_languages`].
+    #[serde(default)]
+    pub show_top_languages: bool,
+    /// Whether to query the contribution calendar and show the current/longest
+    /// streak rows. Off by default since it costs an extra query — see
+    /// [`crate::streak`].
+    #[serde(default)]
+    pub show_streak: bool,
+    /// Whether to sum commit counts across every year since account
+    /// creation and show the "All-time commits" row, instead of just the
+    /// current-year count. Off by default since it costs one query per
+    /// year — see [`crate::github::GithubClient::commit_count_all_time`].
+    #[serde(default)]
+    pub show_commits_all_time: bool,
+    /// Whether to compute and show the "After-hours coder" stat. Off by
+    /// default since it costs an extra history walk per repo.
+    #[serde(default)]
+    pub show_after_hours: bool,
+    /// Weekday work window used by the "After-hours coder" stat.
+    #[serde(default)]
+    pub after_hours: AfterHoursConfig,
+    /// Show `"<cap>+"` instead of the exact Stars/Followers count once it
+    /// reaches this value, e.g. `10000` turns `12345` into `"10k+"`.
+    #[serde(default)]
+    pub stat_cap: Option<u32>,
+    /// Round the Stars/Followers count up to this floor if it's below it,
+    /// so a noisy stat never displays a number the operator finds
+    /// unpresentable.
+    #[serde(default)]
+    pub stat_floor: Option<u32>,
+    /// Most commits [`crate::github::GithubClient::repo_loc`] will walk per
+    /// repo before giving up and marking the "Lines of code" stat
+    /// truncated, so a repo with a pathologically long history can't blow
+    /// memory or run forever. `None` (default) walks full history, bounded
+    /// only by [`crate::github::GithubClient::repo_loc`]'s own page-count
+    /// ceiling.
+    #[serde(default)]
+    pub loc_commit_cap: Option<u32>,
+    /// Skip LOC collection entirely, for a faster render when the "Lines of
+    /// code" row isn't wanted. Off by default.
+    #[serde(default)]
+    pub skip_loc: bool,
+    /// Decimal/thousands-separator convention for every row rendered through
+    /// [`crate::svg::Formatter`], and text direction for the right column's
+    /// labels/values: `"de-de"` for `1.234,5`-style formatting, `"ar-sa"` or
+    /// `"he-il"` for right-to-left, anything else (default) for `"en-us"`-style
+    /// `1,234.5`. Config-only like [`Config::palette`] — a locale choice
+    /// doesn't fit a single CLI flag.
+    #[serde(default)]
+    pub locale: String,
+    /// Quote list for the optional "Quote" row. Empty by default, which
+    /// omits the row. See [`QuoteConfig`].
+    #[serde(default)]
+    pub quote: QuoteConfig,
+    /// City coordinates for the optional "Weather" row. Disabled by default;
+    /// see [`WeatherConfig`].
+    #[serde(default)]
+    pub weather: WeatherConfig,
+    /// Date-ranged messages for the optional "Status" row, in the order
+    /// they should be checked. Empty by default, which omits the row. See
+    /// [`StatusEntry`].
+    #[serde(default)]
+    pub status: Vec<StatusEntry>,
+    /// Career "mini-CV" entries for the optional "Timeline" section, in
+    /// display order. Empty by default, which omits the section. Config-only
+    /// like [`Palette`] — a list of year/role entries doesn't fit a single
+    /// CLI flag. See [`TimelineEntry`].
+    #[serde(default)]
+    pub timeline: Vec<TimelineEntry>,
+    /// Skill/level pairs for the optional "Skills" progress bars, in display
+    /// order. Empty by default, which omits the section. Config-only like
+    /// [`TimelineEntry`], for the same reason. See [`SkillEntry`].
+    #[serde(default)]
+    pub skills: Vec<SkillEntry>,
+    /// `owner/repo` entries for the optional "Maintainer dashboard" section,
+    /// showing each one's open-issue counts by label. Empty by default,
+    /// which omits the section. Unlike [`TimelineEntry`]/[`SkillEntry`],
+    /// each entry drives a live API call rather than being copied straight
+    /// onto the model — see [`crate::maintainer`].
+    #[serde(default)]
+    pub maintained_repos: Vec<String>,
+    /// External command for the optional "Custom" section, run once per
+    /// render and parsed as `key=value` lines. Disabled by default. See
+    /// [`CustomCommandConfig`] for the security caveats of running a
+    /// configured command.
+    #[serde(default)]
+    pub custom_command: CustomCommandConfig,
+    /// User-defined arithmetic formula for the optional "Custom stat" row,
+    /// e.g. `{ "label": "Stars per repo", "expression": "stars / repos" }`.
+    /// Disabled by default. See [`FormulaStat`].
+    #[serde(default)]
+    pub custom_stat: Option<FormulaStat>,
+    /// Whether to discover and run `halfguru-collector-*` executables on
+    /// `PATH` and fold their output into the "Custom" section alongside
+    /// [`Config::custom_command`]. Off by default: unlike `custom_command`,
+    /// this runs whatever matches the naming convention on `PATH`, not just
+    /// a command the operator explicitly named. See [`crate::plugins`].
+    #[serde(default)]
+    pub enable_plugins: bool,
+}
+
+fn default_theme() -> String {
+    "default".to_string()
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            theme: default_theme(),
+            custom_theme: None,
+            ascii_art_file: None,
+            show_avatar: false,
+            palette: String::new(),
+            private_contributions: String::new(),
+            excluded_repos: Vec::new(),
+            allowed_users: Vec::new(),
+            streak: StreakConfig::default(),
+            utc_offset_hours: 0,
+            show_collaborators: false,
+            show_star_history: false,
+            show_spotlight: false,
+            show_contribution_history: false,
+            show_top_languages: false,
+            show_streak: false,
+            show_commits_all_time: false,
+            show_after_hours: false,
+            after_hours: AfterHoursConfig::default(),
+            stat_cap: None,
+            stat_floor: None,
+            loc_commit_cap: None,
+            skip_loc: false,
+            locale: String::new(),
+            quote: QuoteConfig::default(),
+            weather: WeatherConfig::default(),
+            status: Vec::new(),
+            timeline: Vec::new(),
+            skills: Vec::new(),
+            maintained_repos: Vec::new(),
+            custom_command: CustomCommandConfig::default(),
+            custom_stat: None,
+            enable_plugins: false,
+        }
+    }
+}
+
+impl Config {
+    pub fn theme(&self) -> Theme {
+        match self.theme.as_str() {
+            "dark" => Theme::Dark,
+            "dracula" => Theme::Dracula,
+            "gruvbox" => Theme::Gruvbox,
+            "catppuccin" => Theme::Catppuccin,
+            "solarized" => Theme::Solarized,
+            _ => Theme::Default,
+        }
+    }
+
+    /// Colors from [`Self::custom_theme`], if set — takes priority over
+    /// [`Self::theme`] wherever both are threaded into a render.
+    pub fn custom_theme_colors(&self) -> Option<ThemeColors> {
+        self.custom_theme.as_ref().map(CustomThemeConfig::to_theme_colors)
+    }
+
+    pub fn palette(&self) -> Palette {
+        match self.palette.as_str() {
+            "deuteranopia" => Palette::Deuteranopia,
+            "protanopia" => Palette::Protanopia,
+            _ => Palette::Standard,
+        }
+    }
+
+    pub fn private_contributions_mode(&self) -> PrivateContributionsMode {
+        match self.private_contributions.as_str() {
+            "fold" => PrivateContributionsMode::Fold,
+            "show" => PrivateContributionsMode::Show,
+            _ => PrivateContributionsMode::Hidden,
+        }
+    }
+
+    pub fn locale(&self) -> Locale {
+        match self.locale.as_str() {
+            "de-de" | "de" => Locale::DeDe,
+            "ar-sa" | "ar" | "he-il" | "he" => Locale::ArSa,
+            _ => Locale::EnUs,
+        }
+    }
+
+    pub fn stat_limits(&self) -> StatLimits {
+        StatLimits { cap: self.stat_cap, floor: self.stat_floor }
+    }
+
+    /// Whether the on-demand `/card` endpoint may render `username` — always
+    /// true for `owner`, otherwise only if it's on [`Config::allowed_users`].
+    pub fn user_allowed(&self, username: &str, owner: &str) -> bool {
+        username == owner || self.allowed_users.iter().any(|u| u == username)
+    }
+}
+
+pub fn load(path: &Path) -> Result<Config> {
+    let raw = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&raw)?)
+}
@@ -0,0 +1,105 @@
+//! WCAG-ish contrast checking for [`crate::svg::ThemeColors`], so a custom
+//! theme with (say) light gray text on a near-white background gets flagged
+//! instead of silently shipping an unreadable card. [`validate_theme`] runs
+//! on whichever colors a render uses, a custom theme or a built-in one. Not
+//! every built-in theme passes: `Theme::Default`'s title/icon blue and
+//! `Theme::Solarized`'s title blue sit just under the 4.5:1 threshold.
+
+use crate::svg::ThemeColors;
+
+/// Relative luminance per WCAG 2.x, `hex` like `"#fffefe"`. `None` if `hex`
+/// isn't a 6-digit `#rrggbb` string.
+fn relative_luminance(hex: &str) -> Option<f64> {
+    let hex = hex.trim_start_matches('#');
+    if hex.len() != 6 {
+        return None;
+    }
+    let channel = |i: usize| -> Option<f64> {
+        let v = u8::from_str_radix(&hex[i..i + 2], 16).ok()? as f64 / 255.0;
+        Some(if v <= 0.03928 { v / 12.92 } else { ((v + 0.055) / 1.055).powf(2.4) })
+    };
+    let (r, g, b) = (channel(0)?, channel(2)?, channel(4)?);
+    Some(0.2126 * r + 0.7152 * g + 0.0722 * b)
+}
+
+/// WCAG contrast ratio between two colors, from `1.0` (identical) to `21.0`
+/// (black on white). `None` if either color isn't a `#rrggbb` hex string.
+pub fn contrast_ratio(a: &str, b: &str) -> Option<f64> {
+    let (la, lb) = (relative_luminance(a)?, relative_luminance(b)?);
+    let (lighter, darker) = if la > lb { (la, lb) } else { (lb, la) };
+    Some((lighter + 0.05) / (darker + 0.05))
+}
+
+/// WCAG AA's minimum contrast ratio for normal-size text.
+const MIN_CONTRAST: f64 = 4.5;
+
+/// Checks each of `colors`' text-bearing colors against its background,
+/// returning one warning per pairing that falls below [`MIN_CONTRAST`].
+/// For the built-in themes it is empty except for `Theme::Default` (title,
+/// icon) and `Theme::Solarized` (title).
+pub fn validate_theme(colors: &ThemeColors) -> Vec<String> {
+    [("text", colors.text.as_str()), ("title", colors.title.as_str()), ("icon", colors.icon.as_str())]
+        .into_iter()
+        .filter_map(|(label, color)| match contrast_ratio(color, &colors.background) {
+            Some(ratio) if ratio < MIN_CONTRAST => Some(format!(
+                "{label} color {color} has contrast ratio {ratio:.1}:1 against background {} (WCAG AA wants at least {MIN_CONTRAST}:1)",
+                colors.background
+            )),
+            None => Some(format!("{label} color {color} or background {} isn't a #rrggbb hex color; skipping contrast check", colors.background)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn black_on_white_is_max_contrast() {
+        let ratio = contrast_ratio("#000000", "#ffffff").unwrap();
+        assert!((ratio - 21.0).abs() < 0.01, "expected ~21.0, got {ratio}");
+    }
+
+    #[test]
+    fn identical_colors_have_ratio_one() {
+        let ratio = contrast_ratio("#808080", "#808080").unwrap();
+        assert!((ratio - 1.0).abs() < 0.01, "expected ~1.0, got {ratio}");
+    }
+
+    #[test]
+    fn invalid_hex_returns_none() {
+        assert!(contrast_ratio("not-a-color", "#ffffff").is_none());
+        assert!(contrast_ratio("#fff", "#ffffff").is_none());
+    }
+
+    #[test]
+    fn validate_theme_flags_low_contrast_text() {
+        let colors = ThemeColors {
+            background: "#ffffff".to_string(),
+            border: "#cccccc".to_string(),
+            title: "#000000".to_string(),
+            text: "#eeeeee".to_string(),
+            icon: "#000000".to_string(),
+            add_color: "#00ff00".to_string(),
+            del_color: "#ff0000".to_string(),
+        };
+        let warnings = validate_theme(&colors);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("text"));
+    }
+
+    #[test]
+    fn validate_theme_passes_high_contrast() {
+        let colors = ThemeColors {
+            background: "#ffffff".to_string(),
+            border: "#cccccc".to_string(),
+            title: "#000000".to_string(),
+            text: "#000000".to_string(),
+            icon: "#000000".to_string(),
+            add_color: "#00ff00".to_string(),
+            del_color: "#ff0000".to_string(),
+        };
+        assert!(validate_theme(&colors).is_empty());
+    }
+}
@@ -0,0 +1,27 @@
+//! "Contribution history" table: one row per calendar year showing that
+//! year's total contributions, from [`crate::github::GithubClient::contribution_history`].
+//! Opt-in like [`crate::star_history`], since walking every year back to
+//! account creation costs one query per year.
+
+use crate::github::YearlyContributions;
+
+const ROW_HEIGHT: u32 = 18;
+
+/// Vertical space `years` will occupy when rendered, `0` if empty.
+pub fn height(years: &[YearlyContributions]) -> u32 {
+    if years.is_empty() { 0 } else { years.len() as u32 * ROW_HEIGHT }
+}
+
+/// Renders one `"<year>: <total>"` row per entry, stacked downward from
+/// `(x, y)`, oldest year first.
+pub fn render_table(years: &[YearlyContributions], x: u32, y: u32, text_attr: &str) -> String {
+    years
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let row_y = y + i as u32 * ROW_HEIGHT;
+            format!(r#"<text x="{x}" y="{row_y}" {text_attr}>{}: {}</text>"#, entry.year, entry.total)
+        })
+        .collect::<Vec<_>>()
+        .join("\n    ")
+}
@@ -0,0 +1,84 @@
+//! "Contribution mix" section: a small stacked bar plus legend showing the
+//! commit/PR/issue/review split behind [`crate::github::ContributionMix`], so
+//! a glance at the card tells whether someone is mostly a committer,
+//! reviewer, or issue triager. Always computed from live stats, unlike the
+//! purely config-driven [`crate::timeline`] and [`crate::skills`] sections,
+//! but rendered the same way — via `svg.rs`'s [`crate::svg::render_legend`].
+
+use crate::github::ContributionMix;
+use crate::svg::{LegendEntry, PrivateContributionsMode};
+
+const BAR_HEIGHT: u32 = 8;
+const BAR_WIDTH: u32 = 200;
+const LEGEND_GAP: u32 = 16;
+const LEGEND_MAX_PER_ROW: usize = 2;
+
+const COMMIT_COLOR: &str = "#2f80ed";
+const PULL_REQUEST_COLOR: &str = "#4bc44b";
+const ISSUE_COLOR: &str = "#d9534f";
+const REVIEW_COLOR: &str = "#9b59b6";
+
+/// The commit count feeding the bar/legend, private-repo contributions folded
+/// in per `mode` — see [`PrivateContributionsMode`].
+fn visible_commits(mix: &ContributionMix, mode: PrivateContributionsMode) -> u32 {
+    match mode {
+        PrivateContributionsMode::Fold => mix.commits + mix.restricted_commits,
+        PrivateContributionsMode::Hidden | PrivateContributionsMode::Show => mix.commits,
+    }
+}
+
+/// `0` (and thus "not shown") when `mix` has no contributions at all, e.g. a
+/// brand-new account.
+pub fn height(mix: &ContributionMix, mode: PrivateContributionsMode) -> u32 {
+    if visible_commits(mix, mode) + mix.pull_requests + mix.issues + mix.reviews == 0 {
+        0
+    } else {
+        BAR_HEIGHT + LEGEND_GAP + crate::svg::legend_height(4, LEGEND_MAX_PER_ROW)
+    }
+}
+
+pub fn render_mix(mix: &ContributionMix, mode: PrivateContributionsMode, x: u32, y: u32, text_attr: &str) -> String {
+    let commits = visible_commits(mix, mode);
+    let total = commits + mix.pull_requests + mix.issues + mix.reviews;
+    if total == 0 {
+        return String::new();
+    }
+
+    let segments = [
+        (commits, COMMIT_COLOR),
+        (mix.pull_requests, PULL_REQUEST_COLOR),
+        (mix.issues, ISSUE_COLOR),
+        (mix.reviews, REVIEW_COLOR),
+    ];
+    let mut bar_x = x;
+    let bar = segments
+        .iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, color)| {
+            let width = BAR_WIDTH * count / total;
+            let segment = format!(r#"<rect x="{bar_x}" y="{y}" width="{width}" height="{BAR_HEIGHT}" fill="{color}"/>"#);
+            bar_x += width;
+            segment
+        })
+        .collect::<Vec<_>>()
+        .join("\n    ");
+
+    let commits_label = if matches!(mode, PrivateContributionsMode::Show) && mix.restricted_commits > 0 {
+        format!("Commits (+{} private)", mix.restricted_commits)
+    } else {
+        "Commits".to_string()
+    };
+    let entries = [
+        LegendEntry { label: commits_label, color: COMMIT_COLOR.to_string(), share: commits as f64 / total as f64 },
+        LegendEntry {
+            label: "Pull requests".to_string(),
+            color: PULL_REQUEST_COLOR.to_string(),
+            share: mix.pull_requests as f64 / total as f64,
+        },
+        LegendEntry { label: "Issues".to_string(), color: ISSUE_COLOR.to_string(), share: mix.issues as f64 / total as f64 },
+        LegendEntry { label: "Reviews".to_string(), color: REVIEW_COLOR.to_string(), share: mix.reviews as f64 / total as f64 },
+    ];
+    let legend = crate::svg::render_legend(&entries, x, y + LEGEND_GAP, LEGEND_MAX_PER_ROW, text_attr);
+
+    format!("{bar}\n    {legend}")
+}
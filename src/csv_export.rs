@@ -0,0 +1,63 @@
+//! `--format csv`: appends one row per run to a history CSV, for users who'd
+//! rather track their growth in a spreadsheet than re-render past cards to
+//! see how a stat has changed.
+//!
+//! Unlike the SVG/PNG formats in [`crate::export`], which overwrite
+//! `output_path` fresh on every render, this format only ever appends —
+//! [`append`] writes the header once, the first time the file doesn't
+//! already exist, then adds a row and leaves every prior run's row alone.
+
+use crate::error::Result;
+use crate::stats::Stats;
+use std::path::Path;
+
+const HEADER: &str = "timestamp,username,stars,commits,repos,followers,languages,loc_add,loc_del,loc_truncated,loc_skipped,median_issue_response_hours,after_hours_share,profile_views,commits_by_owner,custom_stat";
+
+/// Appends `stats` as one row to the CSV at `path`, writing [`HEADER`] first
+/// if the file doesn't exist yet.
+pub fn append(path: &Path, stats: &Stats) -> Result<()> {
+    use std::io::Write;
+    let is_new = !path.exists();
+    let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+    if is_new {
+        writeln!(file, "{HEADER}")?;
+    }
+    writeln!(file, "{}", row(stats))?;
+    Ok(())
+}
+
+fn row(stats: &Stats) -> String {
+    [
+        chrono::Utc::now().to_rfc3339(),
+        field(&stats.username),
+        stats.stars.to_string(),
+        stats.commits.to_string(),
+        stats.repos.to_string(),
+        stats.followers.to_string(),
+        stats.languages.to_string(),
+        stats.loc_add.to_string(),
+        stats.loc_del.to_string(),
+        stats.loc_truncated.to_string(),
+        stats.loc_skipped.to_string(),
+        opt(stats.median_issue_response_hours),
+        opt(stats.after_hours_share),
+        opt(stats.profile_views),
+        field(stats.commits_by_owner.as_deref().unwrap_or("")),
+        field(stats.custom_stat.as_deref().unwrap_or("")),
+    ]
+    .join(",")
+}
+
+fn opt<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+/// Quotes `value` per RFC 4180 if it contains a comma, quote, or newline;
+/// passes it through unchanged otherwise, since most fields here never need it.
+fn field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,122 @@
+//! Runs a configured external command and parses its stdout as `key=value`
+//! lines for the optional "Custom" section — an escape hatch for personal
+//! data sources (a local script, a value scraped by a cron job into a file
+//! and `cat`'d out) this crate has no dedicated collector for, so users
+//! don't need to write Rust or wait on a feature request to show it.
+//!
+//! `command` is run directly via [`std::process::Command`] with an explicit
+//! argument vector, never through a shell, so configuring it can't be used
+//! to smuggle in shell metacharacters the way a `sh -c "$command"` call
+//! could. There is no other sandboxing: the command runs with the same
+//! privileges as the halfguru process itself, so `Config::custom_command`
+//! should be trusted the same way the rest of `Config` is — this is meant
+//! for running your own script on your own server, not for evaluating
+//! commands supplied by someone else.
+
+use serde::Deserialize;
+use std::io::Read;
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// External command for the optional "Custom" section, as configured under
+/// `Config::custom_command`. `command` being `None` leaves the section
+/// disabled rather than falling back to some default program.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CustomCommandConfig {
+    #[serde(default)]
+    pub command: Option<String>,
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Seconds to wait for the command before killing it and treating the
+    /// section as empty. Default 5.
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+impl Default for CustomCommandConfig {
+    fn default() -> Self {
+        Self { command: None, args: Vec::new(), timeout_secs: default_timeout_secs() }
+    }
+}
+
+fn default_timeout_secs() -> u64 {
+    5
+}
+
+const ROW_HEIGHT: u32 = 22;
+
+/// Runs `config`'s command and parses its stdout into rows, or returns no
+/// rows if it's unconfigured, fails to start, times out, or prints nothing
+/// parseable — like [`crate::weather::fetch`], a failure here shouldn't take
+/// the whole card render down with it.
+pub fn run(config: &CustomCommandConfig) -> Vec<(String, String)> {
+    let Some(command) = &config.command else {
+        return Vec::new();
+    };
+    run_with_timeout(command, &config.args, Duration::from_secs(config.timeout_secs)).map(|output| parse_rows(&output)).unwrap_or_default()
+}
+
+/// Spawns `command args...` and reads its stdout on a helper thread, so the
+/// caller can give up after `timeout` and kill the child instead of
+/// blocking forever on a hung process. `std::process::Command` has no
+/// built-in wait-with-timeout, hence the manual channel + kill.
+///
+/// `pub(crate)` rather than private: [`crate::plugins`] runs its own set of
+/// discovered executables under the same timeout-and-no-shell treatment and
+/// reuses this instead of duplicating it.
+pub(crate) fn run_with_timeout(command: impl AsRef<std::ffi::OsStr>, args: &[String], timeout: Duration) -> Option<Vec<u8>> {
+    let mut child = Command::new(command).args(args).stdout(Stdio::piped()).stderr(Stdio::null()).spawn().ok()?;
+    let mut stdout = child.stdout.take().expect("piped stdout");
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = stdout.read_to_end(&mut buf);
+        let _ = tx.send(buf);
+    });
+
+    let output = match rx.recv_timeout(timeout) {
+        Ok(output) => Some(output),
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            let _ = child.kill();
+            None
+        }
+        Err(mpsc::RecvTimeoutError::Disconnected) => None,
+    };
+    let _ = child.wait();
+    output
+}
+
+/// Parses `key=value` lines into rows, in order, dropping lines without an
+/// `=`.
+fn parse_rows(output: &[u8]) -> Vec<(String, String)> {
+    String::from_utf8_lossy(output)
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .collect()
+}
+
+/// Vertical space `rows` will occupy when rendered, `0` if empty (so
+/// `CardComponent::height`'s "not shown" convention holds without a
+/// separate `is_empty` check at call sites).
+pub fn height(rows: &[(String, String)]) -> u32 {
+    if rows.is_empty() { 0 } else { rows.len() as u32 * ROW_HEIGHT }
+}
+
+/// Renders `rows` as one `key: value` line per row, stacked downward from
+/// `(x, y)`, matching [`crate::timeline::render_timeline`]'s convention so
+/// this module doesn't need to know about themes.
+pub fn render_rows(rows: &[(String, String)], x: u32, y: u32, text_attr: &str) -> String {
+    rows.iter()
+        .enumerate()
+        .map(|(i, (key, value))| {
+            let row_y = y + i as u32 * ROW_HEIGHT;
+            let key = crate::ascii::escape_xml_text(key);
+            let value = crate::ascii::escape_xml_text(value);
+            format!(r#"<text x="{x}" y="{row_y}" {text_attr}>{key}: {value}</text>"#)
+        })
+        .collect::<Vec<_>>()
+        .join("\n    ")
+}
@@ -0,0 +1,13 @@
+//! Shared day-bucketing helper for every date-based stat (streaks, weekday
+//! breakdown, contribution cadence) so they all agree on where a day starts
+//! instead of each computing its own UTC-midnight assumption independently.
+
+use chrono::NaiveDate;
+
+/// Adds `utc_offset_hours` to `date`. A `NaiveDate` has no time of day, so
+/// the offset only moves it by whole days: any offset from -23 to +23 hours
+/// returns `date` unchanged, and a date is never moved to the previous or
+/// next day by a partial-day offset.
+pub fn bucket_day(date: NaiveDate, utc_offset_hours: i32) -> NaiveDate {
+    date + chrono::Duration::hours(utc_offset_hours as i64)
+}
@@ -0,0 +1,85 @@
+//! `--debug-dump`: writes a zip containing the raw (secret-redacted) API
+//! responses behind a render, its computed [`Stats`], the assembled
+//! [`RenderModel`], and basic environment info — so a "my numbers are
+//! wrong" issue report is reproducible without back-and-forth over what the
+//! reporter's setup actually looked like.
+
+use crate::error::Result;
+use crate::github::GithubClient;
+use crate::render::RenderModel;
+use crate::stats::Stats;
+use std::io::Write;
+use std::path::Path;
+
+/// Same subdirectory name [`GithubClient::with_dev_cache`] writes raw
+/// responses under (see `github.rs`'s private `DEV_CACHE_SUBDIR`) — kept in
+/// sync by hand since that constant isn't public.
+const DEV_CACHE_SUBDIR: &str = "dev";
+
+/// Zips `model`'s stats/sections, environment info, and every raw response
+/// found under `dev_cache_dir`'s dev-cache subdirectory into `output_path`.
+/// Callers are expected to have rendered `model` with a fresh
+/// [`GithubClient::with_dev_cache`] pointed at `dev_cache_dir` first, so that
+/// directory holds exactly this run's responses rather than a stale mix of
+/// past ones.
+pub fn write(client: &GithubClient, model: &RenderModel, dev_cache_dir: &Path, output_path: &Path) -> Result<()> {
+    let file = std::fs::File::create(output_path)?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = zip::write::FileOptions::default();
+
+    for (name, raw) in raw_responses(dev_cache_dir) {
+        zip.start_file(format!("responses/{name}"), options)?;
+        zip.write_all(redact(&raw).as_bytes())?;
+    }
+
+    zip.start_file("stats.json", options)?;
+    zip.write_all(serde_json::to_string_pretty(&model.stats)?.as_bytes())?;
+
+    zip.start_file("model.json", options)?;
+    zip.write_all(serde_json::to_string_pretty(&model.to_sections())?.as_bytes())?;
+
+    zip.start_file("environment.txt", options)?;
+    zip.write_all(environment_info(client, &model.stats).as_bytes())?;
+
+    zip.finish()?;
+    Ok(())
+}
+
+fn raw_responses(dev_cache_dir: &Path) -> Vec<(String, String)> {
+    let Ok(entries) = std::fs::read_dir(dev_cache_dir.join(DEV_CACHE_SUBDIR)) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            std::fs::read_to_string(entry.path()).ok().map(|raw| (name, raw))
+        })
+        .collect()
+}
+
+/// Strips anything that looks like a live secret from `raw` before it goes
+/// into the zip — today just the resolved `ACCESS_TOKEN`, if any, so a
+/// response that happened to echo an auth header back doesn't leak it.
+fn redact(raw: &str) -> String {
+    match crate::secrets::resolve("ACCESS_TOKEN") {
+        Some(token) if !token.is_empty() => raw.replace(&token, "[REDACTED]"),
+        _ => raw.to_string(),
+    }
+}
+
+fn environment_info(client: &GithubClient, stats: &Stats) -> String {
+    let rate_limit = client
+        .rate_limit()
+        .map(|r| format!("{}/{} remaining, resets {}", r.remaining, r.limit, r.reset_at))
+        .unwrap_or_else(|e| format!("unavailable: {e}"));
+    format!(
+        "halfguru {version}\nos: {os}\narch: {arch}\nusername: {username}\nACCESS_TOKEN resolved: {has_token}\nrate limit: {rate_limit}\n",
+        version = crate::VERSION,
+        os = std::env::consts::OS,
+        arch = std::env::consts::ARCH,
+        username = stats.username,
+        has_token = crate::secrets::resolve("ACCESS_TOKEN").is_some(),
+    )
+}
@@ -0,0 +1,122 @@
+//! `halfguru doctor`: runs a battery of environment checks and prints
+//! pass/fail results with a remediation hint for each failure, so a report
+//! of "it doesn't work" has somewhere to start besides reading the source.
+
+use crate::github::GithubClient;
+
+struct CheckResult {
+    name: &'static str,
+    ok: bool,
+    detail: String,
+    hint: Option<&'static str>,
+}
+
+fn pass(name: &'static str, detail: impl Into<String>) -> CheckResult {
+    CheckResult { name, ok: true, detail: detail.into(), hint: None }
+}
+
+fn fail(name: &'static str, detail: impl Into<String>, hint: &'static str) -> CheckResult {
+    CheckResult { name, ok: false, detail: detail.into(), hint: Some(hint) }
+}
+
+/// Runs every check and prints the results, then returns whether all of
+/// them passed. Every check runs and prints even after one fails, so the
+/// report always prints in full; `main` picks the exit code from the result.
+pub fn run(client: &GithubClient, config_path: &std::path::Path) -> bool {
+    let checks = vec![
+        check_token_present(),
+        check_token_scopes(client),
+        check_rate_limit(client),
+        check_cache(client),
+        check_config(config_path),
+        check_font(),
+    ];
+
+    let mut all_ok = true;
+    for check in &checks {
+        all_ok &= check.ok;
+        let status = if check.ok { "PASS" } else { "FAIL" };
+        println!("[{status}] {}: {}", check.name, check.detail);
+        if let Some(hint) = check.hint {
+            println!("       hint: {hint}");
+        }
+    }
+    all_ok
+}
+
+fn check_token_present() -> CheckResult {
+    match crate::secrets::resolve("ACCESS_TOKEN") {
+        Some(_) => pass("token present", "ACCESS_TOKEN resolved from env, .env, or the OS keychain"),
+        None => fail(
+            "token present",
+            "ACCESS_TOKEN not found in the environment, a .env file, or the OS keychain",
+            "export ACCESS_TOKEN=<a GitHub personal access token>, or store it under the \"halfguru\" keychain service",
+        ),
+    }
+}
+
+/// Also stands in for "API reachable" and "token valid" — all three fail
+/// together at this one request, so there's no point checking them separately.
+fn check_token_scopes(client: &GithubClient) -> CheckResult {
+    match client.token_scopes() {
+        Ok(scopes) if scopes.iter().any(|s| s == "repo" || s == "public_repo") => {
+            pass("token scopes", format!("granted: {}", scopes.join(", ")))
+        }
+        Ok(scopes) => fail(
+            "token scopes",
+            format!("granted: {} (missing repo/public_repo)", scopes.join(", ")),
+            "regenerate the token with at least the public_repo scope",
+        ),
+        Err(e) => fail("token scopes", format!("request failed: {e}"), "check ACCESS_TOKEN and network connectivity to api.github.com"),
+    }
+}
+
+fn check_rate_limit(client: &GithubClient) -> CheckResult {
+    match client.rate_limit() {
+        Ok(rate_limit) if rate_limit.remaining > 0 => {
+            pass("rate limit", format!("{}/{} remaining, resets {}", rate_limit.remaining, rate_limit.limit, rate_limit.reset_at))
+        }
+        Ok(rate_limit) => fail("rate limit", format!("0/{} remaining, resets {}", rate_limit.limit, rate_limit.reset_at), "wait for the reset or use a different token"),
+        Err(e) => fail("rate limit", format!("query failed: {e}"), "check ACCESS_TOKEN and network connectivity to api.github.com"),
+    }
+}
+
+fn check_cache(client: &GithubClient) -> CheckResult {
+    let path = client.cache_dir().join("loc.json");
+    match std::fs::read_to_string(&path) {
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => pass("cache integrity", format!("no cache yet at {}", path.display())),
+        Err(e) => fail("cache integrity", format!("can't read {}: {e}", path.display()), "check permissions on the cache directory"),
+        Ok(raw) => match serde_json::from_str::<serde_json::Value>(&raw) {
+            Ok(_) => pass("cache integrity", format!("{} parses", path.display())),
+            Err(e) => fail("cache integrity", format!("{} is corrupt: {e}", path.display()), "delete the cache file to force a full re-walk"),
+        },
+    }
+}
+
+fn check_config(config_path: &std::path::Path) -> CheckResult {
+    match crate::config::load(config_path) {
+        Ok(_) => pass("config parse", format!("{} parses", config_path.display())),
+        Err(crate::error::Error::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+            pass("config parse", format!("no config at {} (using defaults)", config_path.display()))
+        }
+        Err(e) => fail("config parse", format!("{} is invalid: {e}", config_path.display()), "fix the JSON syntax or remove the file to fall back to defaults"),
+    }
+}
+
+/// PNG export rasterizes text itself instead of leaving it to an SVG
+/// viewer, so it needs an actual font file on disk — check the platform
+/// font directories for a usable monospace font up front, before a PNG
+/// render fails partway through with missing glyphs.
+fn check_font() -> CheckResult {
+    let candidates: &[&str] = if cfg!(target_os = "windows") {
+        &["C:\\Windows\\Fonts\\consola.ttf", "C:\\Windows\\Fonts\\cour.ttf"]
+    } else if cfg!(target_os = "macos") {
+        &["/System/Library/Fonts/Menlo.ttc", "/Library/Fonts/Menlo.ttc"]
+    } else {
+        &["/usr/share/fonts/truetype/dejavu/DejaVuSansMono.ttf", "/usr/share/fonts/truetype/liberation/LiberationMono-Regular.ttf"]
+    };
+    match candidates.iter().find(|path| std::path::Path::new(path).exists()) {
+        Some(found) => pass("font availability", format!("found {found}")),
+        None => fail("font availability", "no monospace font found for PNG export", "install a monospace font (e.g. DejaVu Sans Mono) or bundle one with halfguru"),
+    }
+}
@@ -0,0 +1,106 @@
+//! `:shortcode:`-style emoji expansion for config-driven row values (quote
+//! rows, status messages) — the same shorthand GitHub renders in READMEs and
+//! issues, so users can decorate cards without pasting raw Unicode into JSON.
+
+/// Expands every `:shortcode:` in `text` to its Unicode emoji, leaving
+/// unrecognized shortcodes untouched rather than dropping them — a
+/// misspelled or unsupported code should stay visible in the rendered card,
+/// not silently disappear.
+pub fn expand_shortcodes(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(':') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start + 1..];
+        match rest.find(':') {
+            Some(end) if end > 0 => match emoji_for(&rest[..end]) {
+                Some(emoji) => {
+                    out.push_str(emoji);
+                    rest = &rest[end + 1..];
+                }
+                None => out.push(':'),
+            },
+            _ => {
+                out.push(':');
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn emoji_for(code: &str) -> Option<&'static str> {
+    Some(match code {
+        "rocket" => "🚀",
+        "tada" => "🎉",
+        "fire" => "🔥",
+        "star" => "⭐",
+        "sparkles" => "✨",
+        "heart" => "❤",
+        "warning" => "⚠",
+        "bulb" => "💡",
+        "wave" => "👋",
+        "computer" => "💻",
+        "thumbsup" => "👍",
+        "checkmark" | "white_check_mark" => "✅",
+        "x" => "❌",
+        "coffee" => "☕",
+        "zap" => "⚡",
+        _ => return None,
+    })
+}
+
+/// Display width of `text` in "monospace columns", counting each emoji this
+/// module can expand as 2 columns (matching how terminals and most
+/// monospace fonts render them) rather than the 1 a plain `.chars().count()`
+/// would give it. Used by [`crate::svg::Row::render`]'s dot-leader math so an
+/// expanded shortcode doesn't throw off the right column's alignment.
+pub fn display_width(text: &str) -> usize {
+    text.chars().map(|ch| if is_wide(ch) { 2 } else { 1 }).sum()
+}
+
+fn is_wide(ch: char) -> bool {
+    matches!(ch as u32, 0x1F300..=0x1FAFF | 0x2600..=0x27BF)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expands_known_shortcode() {
+        assert_eq!(expand_shortcodes("Shipped :rocket:"), "Shipped 🚀");
+    }
+
+    #[test]
+    fn leaves_unknown_shortcode_untouched() {
+        assert_eq!(expand_shortcodes(":not_a_real_emoji:"), ":not_a_real_emoji:");
+    }
+
+    #[test]
+    fn expands_multiple_shortcodes_in_one_string() {
+        assert_eq!(expand_shortcodes(":fire::tada:"), "🔥🎉");
+    }
+
+    #[test]
+    fn leaves_unterminated_colon_untouched() {
+        assert_eq!(expand_shortcodes("a : b"), "a : b");
+    }
+
+    #[test]
+    fn leaves_empty_shortcode_untouched() {
+        assert_eq!(expand_shortcodes("::"), "::");
+    }
+
+    #[test]
+    fn text_without_colons_is_unchanged() {
+        assert_eq!(expand_shortcodes("plain text"), "plain text");
+    }
+
+    #[test]
+    fn display_width_counts_wide_emoji_as_two_columns() {
+        assert_eq!(display_width("ab"), 2);
+        assert_eq!(display_width("🚀"), 2);
+        assert_eq!(display_width("a🚀b"), 4);
+    }
+}
@@ -0,0 +1,67 @@
+use std::fmt;
+
+/// Errors surfaced while fetching stats or rendering the card.
+#[derive(Debug)]
+pub enum Error {
+    Http(reqwest::Error),
+    Graphql(String),
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    Zip(zip::result::ZipError),
+    UserNotFound(String),
+    /// A collection was aborted via a `CancellationToken` before it finished.
+    Cancelled,
+    /// A feature was requested that this build has no way to satisfy, e.g.
+    /// [`crate::export::to_png`] without a rasterizer dependency available.
+    Unsupported(String),
+    /// A descriptive failure that doesn't fit one of the other variants, e.g.
+    /// [`crate::inject`]'s markers missing from a README or
+    /// [`crate::self_update`]'s release-asset lookups. Prefer a dedicated
+    /// variant over reaching for this one when the failure recurs enough to
+    /// warrant its own name.
+    Other(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Http(e) => write!(f, "http request failed: {e}"),
+            Error::Graphql(msg) => write!(f, "graphql error: {msg}"),
+            Error::Io(e) => write!(f, "io error: {e}"),
+            Error::Json(e) => write!(f, "json error: {e}"),
+            Error::Zip(e) => write!(f, "zip error: {e}"),
+            Error::UserNotFound(login) => write!(f, "no GitHub user named \"{login}\""),
+            Error::Cancelled => write!(f, "collection cancelled"),
+            Error::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+            Error::Other(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        Error::Http(e)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Json(e)
+    }
+}
+
+impl From<zip::result::ZipError> for Error {
+    fn from(e: zip::result::ZipError) -> Self {
+        Error::Zip(e)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,77 @@
+//! Controls which file format(s) `main.rs` writes a rendered card in:
+//! rasterizes SVG to PNG for platforms that don't render SVG well (Discord
+//! embeds, some READMEs), picks the append-only CSV path for
+//! [`crate::csv_export`], and builds the embeddable widget for
+//! [`crate::html_widget`].
+//!
+//! There's no actual rasterizer here: a real implementation would reach for
+//! `resvg`/`usvg` (or a similar crate), but this tree has no `Cargo.toml` to
+//! add one to. Hand-rolling an SVG rasterizer (path filling, font shaping,
+//! ...) from scratch is out of scope for what a single module should do, so
+//! [`to_png`] is wired up end-to-end — flag parsing, dispatch from
+//! `main.rs` — but honestly reports [`crate::error::Error::Unsupported`]
+//! instead of silently producing a blank or wrong image. Swap the body of
+//! `to_png` for a real rasterizer call the moment this crate has a manifest
+//! to depend on one.
+
+use crate::error::{Error, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Svg,
+    Png,
+    /// One row appended to a history CSV — see [`crate::csv_export`]. Unlike
+    /// `Svg`/`Png` this doesn't overwrite `output_path`, it appends to it.
+    Csv,
+    /// A self-contained light/dark HTML widget — see [`crate::html_widget`].
+    Html,
+}
+
+/// Parses a comma-separated `--format` value like `"png,svg"` into the
+/// formats to write, in the order given, skipping anything unrecognized.
+/// `None` (the flag omitted) defaults to `[Svg]` so existing invocations
+/// keep writing exactly what they always have.
+pub fn formats_from_flag(value: Option<&str>) -> Vec<Format> {
+    let Some(value) = value else {
+        return vec![Format::Svg];
+    };
+    value
+        .split(',')
+        .map(str::trim)
+        .filter_map(|part| match part {
+            "svg" => Some(Format::Svg),
+            "png" => Some(Format::Png),
+            "csv" => Some(Format::Csv),
+            "html" => Some(Format::Html),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Rasterizes an SVG document to PNG bytes.
+///
+/// Always fails with [`Error::Unsupported`] in this build — see the module
+/// doc comment for why.
+pub fn to_png(_svg: &str) -> Result<Vec<u8>> {
+    Err(Error::Unsupported("PNG export requires an SVG rasterizer (e.g. resvg) that this build doesn't have a dependency on".to_string()))
+}
+
+/// `"dist/card.svg"` -> `"dist/card.png"` for a given [`Format`], `path`
+/// unchanged for [`Format::Svg`].
+pub fn path_for_format(path: &str, format: Format) -> String {
+    match format {
+        Format::Svg => path.to_string(),
+        Format::Png => match path.rsplit_once('.') {
+            Some((stem, _ext)) => format!("{stem}.png"),
+            None => format!("{path}.png"),
+        },
+        Format::Csv => match path.rsplit_once('.') {
+            Some((stem, _ext)) => format!("{stem}.csv"),
+            None => format!("{path}.csv"),
+        },
+        Format::Html => match path.rsplit_once('.') {
+            Some((stem, _ext)) => format!("{stem}.html"),
+            None => format!("{path}.html"),
+        },
+    }
+}
@@ -0,0 +1,1561 @@
+//! Thin wrapper around the GitHub GraphQL API used to collect profile stats.
+
+use crate::error::{Error, Result};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use reqwest::blocking::Client;
+use reqwest::header::{AUTHORIZATION, USER_AGENT};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+const GRAPHQL_ENDPOINT: &str = "https://api.github.com/graphql";
+const GISTS_ENDPOINT: &str = "https://api.github.com/gists";
+const CONTENTS_ENDPOINT: &str = "https://api.github.com/repos";
+const USER_ENDPOINT: &str = "https://api.github.com/user";
+const CACHE_FILE: &str = "loc.json";
+const STARGAZER_CACHE_FILE: &str = "stargazers.json";
+const DEV_CACHE_SUBDIR: &str = "dev";
+
+/// Where cache files live when the caller doesn't pass `--cache-dir`: the
+/// platform cache directory (`%LOCALAPPDATA%\halfguru` on Windows,
+/// `~/.cache/halfguru` on Linux, `~/Library/Caches/halfguru` on macOS), or
+/// `./cache` relative to the working directory if the platform doesn't
+/// expose one. Keeps a plain `cargo run` from scattering files across
+/// whatever directory happens to be current.
+fn default_cache_dir() -> std::path::PathBuf {
+    dirs::cache_dir().map(|dir| dir.join("halfguru")).unwrap_or_else(|| Path::new("cache").to_path_buf())
+}
+
+/// Bumped whenever [`CacheEnvelope`]'s shape changes so old cache files can be
+/// migrated instead of silently discarded on `serde_json::from_str` failure.
+const CACHE_VERSION: u32 = 3;
+
+/// One repo's cached LOC totals, as stored in `cache/loc.json`.
+///
+/// `head_oid` is the default branch commit the walk that produced
+/// `additions`/`deletions` last saw — [`GithubClient::total_loc`] only trusts
+/// this entry while the repo's current HEAD still matches it, and re-walks
+/// history from scratch the moment a new commit lands, rather than caching
+/// forever the way version 2 of this file did. `None` for entries written
+/// before this field existed, which forces a re-walk on next use.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LocCacheEntry {
+    #[serde(default)]
+    head_oid: Option<String>,
+    additions: u64,
+    deletions: u64,
+    /// Commits [`GithubClient::repo_loc`] actually walked to produce this
+    /// entry — not used for cache validity (that's `head_oid`'s job), just
+    /// recorded because it's cheap and useful for debugging a suspicious total.
+    #[serde(default)]
+    commits: u32,
+    truncated: bool,
+}
+
+/// On-disk shape of `cache/loc.json`. Wrapping the raw map in a versioned
+/// envelope means future fields can be added with `#[serde(default)]` without
+/// breaking deserialization of caches written by older binaries.
+///
+/// `entries` is keyed by repository node ID (see [`OwnedRepo::id`]), not
+/// name, so renames/transfers hit the existing entry instead of re-walking
+/// history under a "new" key. See [`LocCacheEntry`] for what's stored per repo.
+#[derive(Serialize, Deserialize)]
+struct CacheEnvelope {
+    #[serde(default)]
+    version: u32,
+    #[serde(default)]
+    entries: HashMap<String, LocCacheEntry>,
+}
+
+/// On-disk shape of `cache/stargazers.json`, mirroring [`CacheEnvelope`] but
+/// keyed the same way (by [`OwnedRepo::id`]) for a repo's raw stargazer
+/// dates instead of LOC totals. Dates are stored as `%Y-%m-%d` strings
+/// rather than deriving through `chrono`, matching how dates are already
+/// parsed out of raw JSON elsewhere in this file.
+#[derive(Serialize, Deserialize)]
+struct StargazerCacheEnvelope {
+    #[serde(default)]
+    version: u32,
+    #[serde(default)]
+    entries: HashMap<String, Vec<String>>,
+}
+
+/// Retry behavior for transient GraphQL failures: how many attempts,
+/// how long to wait between them, and how that wait grows and jitters.
+/// Applied by [`GithubClient::query`] to every GraphQL request. The REST
+/// calls (gist upload, contents API, contributor stats, token scopes) make
+/// a single attempt and don't consult it.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    /// Fraction of the computed delay to randomize, e.g. `0.2` = ±20%.
+    pub jitter: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self { max_attempts: 4, base_delay: Duration::from_millis(200), max_delay: Duration::from_secs(5), jitter: 0.2 }
+    }
+}
+
+impl RetryPolicy {
+    /// Exponential backoff for `attempt` (1-based), capped at `max_delay`
+    /// and jittered by up to `jitter` in either direction.
+    fn delay_for(&self, attempt: u32) -> Duration {
+        let backoff = self.base_delay.saturating_mul(1u32 << attempt.saturating_sub(1).min(16));
+        let capped = backoff.min(self.max_delay);
+        let jitter_frac = (pseudo_random() * 2.0 - 1.0) * self.jitter;
+        capped.mul_f64((1.0 + jitter_frac).max(0.0))
+    }
+
+    fn is_retryable(&self, error: &Error) -> bool {
+        match error {
+            Error::Http(e) => e.is_timeout() || e.is_connect() || e.status().is_some_and(|s| s.is_server_error()),
+            Error::Graphql(msg) => msg.contains("RATE_LIMITED"),
+            _ => false,
+        }
+    }
+}
+
+/// A dependency-free stand-in for jitter randomness: the low bits of the
+/// current time. Not suitable for anything security-sensitive — it only
+/// needs to spread out retries enough to avoid a thundering herd.
+fn pseudo_random() -> f64 {
+    let nanos = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map(|d| d.subsec_nanos()).unwrap_or(0);
+    (nanos % 1_000_000) as f64 / 1_000_000.0
+}
+
+/// Cooperative cancellation for an in-flight stat collection. Cloning shares
+/// the same underlying flag, so a server/daemon holding one clone can cancel
+/// a `GithubClient` call running with another (e.g. because the client
+/// dropped the request, or config was hot-reloaded).
+#[derive(Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self(Arc::new(AtomicBool::new(false)))
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+#[derive(Clone)]
+pub struct GithubClient {
+    http: Client,
+    token: String,
+    dev_cache: bool,
+    cache_dir: std::path::PathBuf,
+    retry_policy: RetryPolicy,
+    cancellation: Option<CancellationToken>,
+}
+
+impl GithubClient {
+    pub fn new(token: String) -> Self {
+        Self {
+            http: Client::new(),
+            token,
+            dev_cache: false,
+            cache_dir: default_cache_dir(),
+            retry_policy: RetryPolicy::default(),
+            cancellation: None,
+        }
+    }
+
+    /// Enables on-disk caching of raw GraphQL responses under `<cache-dir>/dev/`,
+    /// keyed by a hash of the query and variables, so iterating on SVG/layout
+    /// code doesn't repeatedly hit the API or burn quota. Responses go stale
+    /// the moment the underlying data changes, so this is for local
+    /// development only — never enable it in a scheduled/CI run.
+    pub fn with_dev_cache(mut self, enabled: bool) -> Self {
+        self.dev_cache = enabled;
+        self
+    }
+
+    /// Overrides the directory the LOC cache and (with [`Self::with_dev_cache`])
+    /// dev response cache are written under, in place of the platform default
+    /// from [`default_cache_dir`].
+    pub fn with_cache_dir(mut self, dir: std::path::PathBuf) -> Self {
+        self.cache_dir = dir;
+        self
+    }
+
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Threads a [`CancellationToken`] through this client's GraphQL calls,
+    /// checked before each request, between retries, and between pages of
+    /// the paged walks. The REST calls don't check it.
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    fn check_cancelled(&self) -> Result<()> {
+        if self.cancellation.as_ref().is_some_and(CancellationToken::is_cancelled) {
+            return Err(Error::Cancelled);
+        }
+        Ok(())
+    }
+
+    fn query(&self, query: &str, variables: Value) -> Result<Value> {
+        self.check_cancelled()?;
+        if self.dev_cache {
+            if let Some(cached) = load_dev_cache(&self.cache_dir, query, &variables) {
+                return Ok(cached);
+            }
+        }
+
+        let mut attempt = 0;
+        let resp = loop {
+            attempt += 1;
+            match self.query_once(query, &variables) {
+                Ok(resp) => break resp,
+                Err(e) if attempt < self.retry_policy.max_attempts && self.retry_policy.is_retryable(&e) => {
+                    std::thread::sleep(self.retry_policy.delay_for(attempt));
+                    self.check_cancelled()?;
+                }
+                Err(e) => return Err(e),
+            }
+        };
+
+        if self.dev_cache {
+            save_dev_cache(&self.cache_dir, query, &variables, &resp);
+        }
+        Ok(resp)
+    }
+
+    #[tracing::instrument(skip(self, query, variables))]
+    fn query_once(&self, query: &str, variables: &Value) -> Result<Value> {
+        let body = json!({ "query": query, "variables": variables });
+        let resp = self
+            .http
+            .post(GRAPHQL_ENDPOINT)
+            .header(AUTHORIZATION, format!("bearer {}", self.token))
+            .header(USER_AGENT, "halfguru")
+            .json(&body)
+            .send()?
+            .json::<Value>()?;
+        if let Some(errors) = resp.get("errors") {
+            return Err(Error::Graphql(errors.to_string()));
+        }
+        Ok(resp)
+    }
+
+    /// Confirms `username` resolves to a real GitHub user. Every other method
+    /// silently reads `0`/`null` for a nonexistent login (GraphQL just returns
+    /// `user: null`), so callers should run this once up front and surface
+    /// [`Error::UserNotFound`] instead of a card full of misleading zeros.
+    pub fn verify_user(&self, username: &str) -> Result<()> {
+        let data = self.query(USER_EXISTS_QUERY, json!({ "login": username }))?;
+        if data["data"]["user"].is_null() {
+            return Err(Error::UserNotFound(username.to_string()));
+        }
+        Ok(())
+    }
+
+    /// Creates a new gist containing `filename`/`content`, or updates
+    /// `gist_id` in place if given, so repeat runs hotlink a stable URL
+    /// instead of accumulating new gists. Returns the gist's HTML URL.
+    pub fn upload_gist(&self, gist_id: Option<&str>, filename: &str, content: &str) -> Result<String> {
+        let body = json!({
+            "description": "halfguru generated card",
+            "public": false,
+            "files": { filename: { "content": content } },
+        });
+        let request = match gist_id {
+            Some(id) => self.http.patch(format!("{GISTS_ENDPOINT}/{id}")),
+            None => self.http.post(GISTS_ENDPOINT),
+        };
+        let resp = request
+            .header(AUTHORIZATION, format!("bearer {}", self.token))
+            .header(USER_AGENT, "halfguru")
+            .json(&body)
+            .send()?
+            .error_for_status()?
+            .json::<Value>()?;
+        Ok(resp["html_url"].as_str().unwrap_or_default().to_string())
+    }
+
+    /// Creates or updates `path` on `branch` of `owner/repo` via the contents
+    /// API, so generated artifacts can land on an `assets` branch (or a
+    /// separate repo entirely) instead of the working tree. Skips the write
+    /// entirely when the existing file's content already matches, so a CI
+    /// job that regenerates unchanged output doesn't create empty commits.
+    pub fn put_file(&self, owner: &str, repo: &str, branch: &str, path: &str, content: &str, message: &str) -> Result<()> {
+        let url = format!("{CONTENTS_ENDPOINT}/{owner}/{repo}/contents/{path}");
+        let existing = self
+            .http
+            .get(&url)
+            .header(AUTHORIZATION, format!("bearer {}", self.token))
+            .header(USER_AGENT, "halfguru")
+            .query(&[("ref", branch)])
+            .send()?;
+
+        let mut sha = None;
+        if existing.status().is_success() {
+            let body = existing.json::<Value>()?;
+            let existing_b64 = body["content"].as_str().unwrap_or_default().replace('\n', "");
+            if BASE64.decode(existing_b64).ok().as_deref() == Some(content.as_bytes()) {
+                return Ok(());
+            }
+            sha = body["sha"].as_str().map(str::to_string);
+        }
+
+        let body = json!({
+            "message": message,
+            "content": BASE64.encode(content),
+            "branch": branch,
+            "sha": sha,
+        });
+        self.http
+            .put(&url)
+            .header(AUTHORIZATION, format!("bearer {}", self.token))
+            .header(USER_AGENT, "halfguru")
+            .json(&body)
+            .send()?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    /// Fetches the stats shown on a `repo-card` (see `repo_card.rs`).
+    pub fn repo_info(&self, owner: &str, name: &str) -> Result<RepoInfo> {
+        let data = self.query(REPO_INFO_QUERY, json!({ "owner": owner, "name": name }))?;
+        let repo = &data["data"]["repository"];
+        if repo.is_null() {
+            return Err(Error::Graphql(format!("no repository {owner}/{name}")));
+        }
+        Ok(RepoInfo {
+            name: name.to_string(),
+            stars: repo["stargazerCount"].as_u64().unwrap_or(0) as u32,
+            forks: repo["forkCount"].as_u64().unwrap_or(0) as u32,
+            open_issues: repo["issues"]["totalCount"].as_u64().unwrap_or(0) as u32,
+            primary_language: repo["primaryLanguage"]["name"].as_str().map(str::to_string),
+            latest_release: repo["releases"]["nodes"][0]["tagName"].as_str().map(str::to_string),
+        })
+    }
+
+    /// Sums `stargazerCount` across every owned repo, paging
+    /// [`OWNED_REPOS_PAGE_LIMIT`] pages deep (100 repos per page) so an
+    /// account with more than 100 repos isn't silently undercounted.
+    pub fn star_count(&self, username: &str) -> Result<u32> {
+        let mut total = 0u32;
+        let mut cursor: Option<String> = None;
+        for _ in 0..OWNED_REPOS_PAGE_LIMIT {
+            self.check_cancelled()?;
+            let data = self.query(STAR_COUNT_QUERY, json!({ "login": username, "after": cursor }))?;
+            let repositories = &data["data"]["user"]["repositories"];
+            let repos = repositories["nodes"].as_array().cloned().unwrap_or_default();
+            total += repos
+                .iter()
+                .map(|r| r["stargazers"]["totalCount"].as_u64().unwrap_or(0) as u32)
+                .sum::<u32>();
+            let page_info = &repositories["pageInfo"];
+            if !page_info["hasNextPage"].as_bool().unwrap_or(false) {
+                break;
+            }
+            cursor = page_info["endCursor"].as_str().map(str::to_string);
+        }
+        Ok(total)
+    }
+
+    pub fn commit_count(&self, username: &str) -> Result<u32> {
+        let data = self.query(COMMIT_COUNT_QUERY, json!({ "login": username }))?;
+        Ok(data["data"]["user"]["contributionsCollection"]["totalCommitContributions"]
+            .as_u64()
+            .unwrap_or(0) as u32)
+    }
+
+    /// The full commit/PR/issue/review split behind the "Contribution mix"
+    /// section, in one round trip rather than four — see [`ContributionMix`].
+    pub fn contribution_mix(&self, username: &str) -> Result<ContributionMix> {
+        let data = self.query(CONTRIBUTION_MIX_QUERY, json!({ "login": username }))?;
+        let collection = &data["data"]["user"]["contributionsCollection"];
+        Ok(ContributionMix {
+            commits: collection["totalCommitContributions"].as_u64().unwrap_or(0) as u32,
+            pull_requests: collection["totalPullRequestContributions"].as_u64().unwrap_or(0) as u32,
+            issues: collection["totalIssueContributions"].as_u64().unwrap_or(0) as u32,
+            reviews: collection["totalPullRequestReviewContributions"].as_u64().unwrap_or(0) as u32,
+            restricted_commits: collection["restrictedContributionsCount"].as_u64().unwrap_or(0) as u32,
+        })
+    }
+
+    /// Commit contributions bucketed by the repository owner's relationship
+    /// to `username` — own repos, org repos, or other people's repos — for
+    /// the "Commits by owner" stat row. Pages through
+    /// `commitContributionsByRepository` rather than the plain commit count,
+    /// since that's the only field carrying per-repository ownership.
+    pub fn commits_by_owner_type(&self, username: &str) -> Result<CommitOwnershipSplit> {
+        let data = self.query(COMMIT_OWNERSHIP_QUERY, json!({ "login": username }))?;
+        let by_repo = data["data"]["user"]["contributionsCollection"]["commitContributionsByRepository"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default();
+        let mut split = CommitOwnershipSplit::default();
+        for entry in by_repo {
+            let count = entry["contributions"]["totalCount"].as_u64().unwrap_or(0) as u32;
+            let owner_login = entry["repository"]["owner"]["login"].as_str().unwrap_or("");
+            let owner_type = entry["repository"]["owner"]["__typename"].as_str().unwrap_or("");
+            if owner_type == "Organization" {
+                split.org += count;
+            } else if owner_login == username {
+                split.own += count;
+            } else {
+                split.other += count;
+            }
+        }
+        Ok(split)
+    }
+
+    pub fn follower_count(&self, username: &str) -> Result<u32> {
+        let data = self.query(FOLLOWER_COUNT_QUERY, json!({ "login": username }))?;
+        Ok(data["data"]["user"]["followers"]["totalCount"].as_u64().unwrap_or(0) as u32)
+    }
+
+    /// Owned repository count straight from GraphQL's `totalCount`, without
+    /// paging through nodes — an independent path from [`Self::list_owned_repos`]
+    /// for [`crate::verify`] to cross-check against, since a missed page there
+    /// would silently undercount past 100 repos.
+    pub fn repo_count(&self, username: &str) -> Result<u32> {
+        let data = self.query(REPO_COUNT_QUERY, json!({ "login": username }))?;
+        Ok(data["data"]["user"]["repositories"]["totalCount"].as_u64().unwrap_or(0) as u32)
+    }
+
+    /// URL of `username`'s avatar image, for the left column's optional
+    /// avatar block (see [`crate::avatar`]/`Config::show_avatar`). GitHub
+    /// always has one — a default identicon if the user never uploaded a
+    /// picture — so this never needs an `Option`.
+    pub fn avatar_url(&self, username: &str) -> Result<String> {
+        let data = self.query(AVATAR_URL_QUERY, json!({ "login": username }))?;
+        Ok(data["data"]["user"]["avatarUrl"].as_str().unwrap_or_default().to_string())
+    }
+
+    /// Additions/deletions attributed to `username` in `repo` by GitHub's REST
+    /// contributor stats endpoint, as an independent cross-check for
+    /// [`Self::repo_loc`]'s GraphQL history walk (see [`crate::verify`]). The
+    /// endpoint computes its stats asynchronously and returns `202` with an
+    /// empty body while that's in progress; that's treated the same as "no
+    /// data yet" rather than an error, since retrying is the caller's choice.
+    pub fn repo_contributor_loc(&self, owner: &str, repo: &str, username: &str) -> Result<Option<(u64, u64)>> {
+        let url = format!("{CONTENTS_ENDPOINT}/{owner}/{repo}/stats/contributors");
+        let resp = self
+            .http
+            .get(&url)
+            .header(AUTHORIZATION, format!("bearer {}", self.token))
+            .header(USER_AGENT, "halfguru")
+            .send()?
+            .error_for_status()?;
+        if resp.status() == reqwest::StatusCode::ACCEPTED {
+            return Ok(None);
+        }
+        let contributors = resp.json::<Vec<Value>>()?;
+        let Some(entry) = contributors.into_iter().find(|c| c["author"]["login"].as_str() == Some(username)) else {
+            return Ok(Some((0, 0)));
+        };
+        let mut additions = 0u64;
+        let mut deletions = 0u64;
+        for week in entry["weeks"].as_array().cloned().unwrap_or_default() {
+            additions += week["a"].as_u64().unwrap_or(0);
+            deletions += week["d"].as_u64().unwrap_or(0);
+        }
+        Ok(Some((additions, deletions)))
+    }
+
+    /// Pages [`OWNED_REPOS_PAGE_LIMIT`] pages deep (100 repos per page) so
+    /// an account with more than 100 repos isn't silently undercounted,
+    /// like [`Self::star_count`].
+    pub fn list_owned_repos(&self, username: &str) -> Result<Vec<OwnedRepo>> {
+        let mut repos = Vec::new();
+        let mut cursor: Option<String> = None;
+        for _ in 0..OWNED_REPOS_PAGE_LIMIT {
+            self.check_cancelled()?;
+            let data = self.query(OWNED_REPOS_QUERY, json!({ "login": username, "after": cursor }))?;
+            let repositories = &data["data"]["user"]["repositories"];
+            let nodes = repositories["nodes"].as_array().cloned().unwrap_or_default();
+            repos.extend(nodes.into_iter().map(|r| OwnedRepo {
+                id: r["id"].as_str().unwrap_or_default().to_string(),
+                name: r["name"].as_str().unwrap_or_default().to_string(),
+                head_oid: r["defaultBranchRef"]["target"]["oid"].as_str().map(str::to_string),
+            }));
+            let page_info = &repositories["pageInfo"];
+            if !page_info["hasNextPage"].as_bool().unwrap_or(false) {
+                break;
+            }
+            cursor = page_info["endCursor"].as_str().map(str::to_string);
+        }
+        Ok(repos)
+    }
+
+    /// Sums each language's byte count across owned, non-fork repos for the
+    /// "Top Languages" section, paging [`OWNED_REPOS_PAGE_LIMIT`] pages deep
+    /// like [`Self::star_count`]/[`Self::list_owned_repos`]. Forks are
+    /// excluded so a single vendored dependency someone forked doesn't
+    /// swamp the totals with a language they didn't actually write. Returned
+    /// unsorted and un-percentaged — see [`crate::top_languages`] for that.
+    pub fn language_totals(&self, username: &str) -> Result<Vec<(String, u64)>> {
+        let mut totals: std::collections::HashMap<String, u64> = std::collections::HashMap::new();
+        let mut cursor: Option<String> = None;
+        for _ in 0..OWNED_REPOS_PAGE_LIMIT {
+            self.check_cancelled()?;
+            let data = self.query(
+                LANGUAGE_TOTALS_QUERY,
+                json!({ "login": username, "after": cursor, "languagesPerRepo": LANGUAGES_PER_REPO_LIMIT }),
+            )?;
+            let repositories = &data["data"]["user"]["repositories"];
+            let nodes = repositories["nodes"].as_array().cloned().unwrap_or_default();
+            for repo in nodes {
+                for edge in repo["languages"]["edges"].as_array().cloned().unwrap_or_default() {
+                    let Some(name) = edge["node"]["name"].as_str() else { continue };
+                    let size = edge["size"].as_u64().unwrap_or(0);
+                    *totals.entry(name.to_string()).or_insert(0) += size;
+                }
+            }
+            let page_info = &repositories["pageInfo"];
+            if !page_info["hasNextPage"].as_bool().unwrap_or(false) {
+                break;
+            }
+            cursor = page_info["endCursor"].as_str().map(str::to_string);
+        }
+        Ok(totals.into_iter().collect())
+    }
+
+    /// Walks the default branch history of `repo` a page at a time, summing
+    /// additions/deletions authored by `username` into a running total
+    /// rather than collecting every commit node first, so a repo with
+    /// hundreds of thousands of commits costs one page of memory instead of
+    /// its entire history. Stops early, with the returned `bool` set, once
+    /// `commit_cap` commits have been seen — `None` still stops at
+    /// [`COMMIT_HISTORY_PAGE_LIMIT`] pages, an honest cap so a single repo
+    /// can't page forever, like [`Self::repo_stargazer_dates`].
+    ///
+    /// Starts each repo at [`COMMIT_HISTORY_PAGE_SIZE`] commits per page, but
+    /// a history page this wide can be expensive enough for GitHub to give
+    /// up on with a timeout or a generic `"something went wrong"` GraphQL
+    /// error rather than a normal rate-limit response — see
+    /// [`is_page_too_heavy`]. Rather than failing the whole repo, the same
+    /// page is retried at half the size, down to [`COMMIT_HISTORY_MIN_PAGE_SIZE`].
+    ///
+    /// Returns `(additions, deletions, commits, truncated)` — `commits` is
+    /// the number actually walked, recorded in [`LocCacheEntry`] alongside
+    /// the totals it produced.
+    #[tracing::instrument(skip(self))]
+    pub fn repo_loc(&self, username: &str, repo: &str, commit_cap: Option<u32>) -> Result<(u64, u64, u32, bool)> {
+        let mut additions = 0u64;
+        let mut deletions = 0u64;
+        let mut seen = 0u32;
+        let mut truncated = false;
+        let mut cursor: Option<String> = None;
+        let mut page_size = COMMIT_HISTORY_PAGE_SIZE;
+        for _ in 0..COMMIT_HISTORY_PAGE_LIMIT {
+            self.check_cancelled()?;
+            let data = loop {
+                let variables = json!({ "owner": username, "name": repo, "author": username, "after": cursor, "first": page_size });
+                match self.query(REPO_COMMIT_LOC_PAGE_QUERY, variables) {
+                    Ok(data) => break data,
+                    Err(e) if page_size > COMMIT_HISTORY_MIN_PAGE_SIZE && is_page_too_heavy(&e) => {
+                        page_size = (page_size / 2).max(COMMIT_HISTORY_MIN_PAGE_SIZE);
+                        self.check_cancelled()?;
+                    }
+                    Err(e) => return Err(e),
+                }
+            };
+            let history = &data["data"]["repository"]["defaultBranchRef"]["target"]["history"];
+            let edges = history["edges"].as_array().cloned().unwrap_or_default();
+            if edges.is_empty() {
+                break;
+            }
+            for edge in &edges {
+                additions += edge["node"]["additions"].as_u64().unwrap_or(0);
+                deletions += edge["node"]["deletions"].as_u64().unwrap_or(0);
+                seen += 1;
+                if commit_cap.is_some_and(|cap| seen >= cap) {
+                    truncated = true;
+                    break;
+                }
+            }
+            if truncated {
+                break;
+            }
+            let page_info = &history["pageInfo"];
+            if !page_info["hasNextPage"].as_bool().unwrap_or(false) {
+                break;
+            }
+            cursor = page_info["endCursor"].as_str().map(str::to_string);
+        }
+        Ok((additions, deletions, seen, truncated))
+    }
+
+    /// Co-author logins mentioned in commit trailers across `repo`'s history
+    /// authored by `username`, from one [`REPO_HISTORY_QUERY`] request. That
+    /// query is separate from the paged walk [`Self::repo_loc`] makes, so
+    /// this stat costs a history query of its own per repo.
+    fn repo_collaborators(&self, username: &str, repo: &str) -> Result<Vec<String>> {
+        let data = self.query(REPO_HISTORY_QUERY, json!({ "owner": username, "name": repo, "author": username }))?;
+        let history = &data["data"]["repository"]["defaultBranchRef"]["target"]["history"];
+        let edges = history["edges"].as_array().cloned().unwrap_or_default();
+        Ok(edges
+            .iter()
+            .flat_map(|edge| crate::collaborators::extract_co_authors(edge["node"]["message"].as_str().unwrap_or_default()))
+            .collect())
+    }
+
+    /// The `limit` most frequent co-authors across every owned repository,
+    /// most frequent first, excluding `username` itself. Not cached like
+    /// [`Self::total_loc`] since it's opt-in and only walked when a card
+    /// actually asks for it.
+    pub fn top_collaborators(&self, username: &str, limit: usize) -> Result<Vec<String>> {
+        let repos = self.list_owned_repos(username)?;
+        let mut logins = Vec::new();
+        for repo in repos {
+            self.check_cancelled()?;
+            logins.extend(self.repo_collaborators(username, &repo.name)?);
+        }
+        Ok(crate::collaborators::top_collaborators(logins, username, limit))
+    }
+
+    /// Time-to-first-response, in hours, for each of `repo`'s 50 most recent
+    /// issues that has at least one comment. Issues with no comments yet are
+    /// excluded rather than counted as an infinite wait.
+    fn repo_issue_response_hours(&self, owner: &str, repo: &str) -> Result<Vec<f64>> {
+        let data = self.query(ISSUE_RESPONSE_QUERY, json!({ "owner": owner, "name": repo }))?;
+        let issues = data["data"]["repository"]["issues"]["nodes"].as_array().cloned().unwrap_or_default();
+        let mut hours = Vec::new();
+        for issue in issues {
+            let created = issue["createdAt"].as_str().and_then(parse_rfc3339);
+            let first_comment = issue["comments"]["nodes"][0]["createdAt"].as_str().and_then(parse_rfc3339);
+            if let (Some(created), Some(first_comment)) = (created, first_comment) {
+                hours.push((first_comment - created).num_minutes() as f64 / 60.0);
+            }
+        }
+        Ok(hours)
+    }
+
+    /// Median time-to-first-response across issues in every owned repository,
+    /// sampled from each repo's most recent issues. `None` if the sample had
+    /// no answered issues anywhere.
+    pub fn median_issue_response_hours(&self, username: &str) -> Result<Option<f64>> {
+        let repos = self.list_owned_repos(username)?;
+        let mut hours = Vec::new();
+        for repo in repos {
+            self.check_cancelled()?;
+            hours.extend(self.repo_issue_response_hours(username, &repo.name)?);
+        }
+        Ok(median(hours))
+    }
+
+    /// Commit timestamps from `repo`'s history authored by `username`.
+    /// Runs the same [`REPO_HISTORY_QUERY`] as [`Self::repo_collaborators`],
+    /// as a request of its own, so this stat costs one extra history query
+    /// per repo.
+    fn repo_commit_timestamps(&self, username: &str, repo: &str) -> Result<Vec<chrono::DateTime<chrono::Utc>>> {
+        let data = self.query(REPO_HISTORY_QUERY, json!({ "owner": username, "name": repo, "author": username }))?;
+        let history = &data["data"]["repository"]["defaultBranchRef"]["target"]["history"];
+        let edges = history["edges"].as_array().cloned().unwrap_or_default();
+        Ok(edges
+            .iter()
+            .filter_map(|edge| edge["node"]["committedDate"].as_str().and_then(parse_rfc3339))
+            .collect())
+    }
+
+    /// Share of commits across every owned repository made outside `config`'s
+    /// weekday work window, per [`crate::afterhours::after_hours_share`].
+    /// `None` if there are no commits to classify.
+    pub fn after_hours_share(&self, username: &str, utc_offset_hours: i32, config: &crate::afterhours::AfterHoursConfig) -> Result<Option<f64>> {
+        let repos = self.list_owned_repos(username)?;
+        let mut timestamps = Vec::new();
+        for repo in repos {
+            self.check_cancelled()?;
+            timestamps.extend(self.repo_commit_timestamps(username, &repo.name)?);
+        }
+        Ok(crate::afterhours::after_hours_share(&timestamps, utc_offset_hours, config))
+    }
+
+    /// Sums `repo_loc` across every owned repository, caching per-repo results on
+    /// disk so repeat runs don't re-walk full history every time. Cached by
+    /// [`OwnedRepo::id`] (a stable GraphQL node ID) rather than name, so a
+    /// rename or transfer doesn't look like a brand-new repo and trigger a
+    /// full history re-walk. A cached [`LocCacheEntry`] is only trusted while
+    /// its `head_oid` still matches the repo's current default branch HEAD —
+    /// a new commit invalidates it and triggers a fresh walk, rather than
+    /// caching a repo's totals forever once computed. The cache is saved
+    /// after every newly-walked repo rather than once at the end, so a
+    /// crashed or CI-timeout-killed run resumes from the last completed repo
+    /// instead of re-walking everything from scratch — `repo_loc` itself
+    /// pages one repo's history a page at a time, so there's no
+    /// partway-through-a-repo cursor to checkpoint, only which repos are
+    /// already done.
+    ///
+    /// Walks up to [`LOC_CONCURRENCY`] repos at once — one thread per slot,
+    /// like [`crate::leaderboard::fetch_entries`] — since each repo's history
+    /// walk is an independent round trip and `GithubClient` is cheap to
+    /// clone. Rate limits are still respected: [`Self::query`]'s retry policy
+    /// backs off on a rate-limited response regardless of which thread hit
+    /// it, so this only bounds how many requests can be in flight at once,
+    /// not whether they're throttled.
+    ///
+    /// `commit_cap` bounds how many commits `repo_loc` will walk per repo —
+    /// see its doc comment. The returned `bool` is `true` if any repo hit
+    /// that cap, so the caller can show a "truncated" marker rather than
+    /// silently under-reporting.
+    #[tracing::instrument(skip(self))]
+    pub fn total_loc(&self, username: &str, commit_cap: Option<u32>) -> Result<(u64, u64, bool)> {
+        let repos = self.list_owned_repos(username)?;
+        let cache = Mutex::new(load_cache(&self.cache_dir));
+        let queue = Mutex::new(repos.into_iter());
+
+        let results = thread::scope(|scope| {
+            (0..LOC_CONCURRENCY)
+                .map(|_| {
+                    scope.spawn(|| -> Result<(u64, u64, bool)> {
+                        let mut add = 0u64;
+                        let mut del = 0u64;
+                        let mut truncated = false;
+                        loop {
+                            self.check_cancelled()?;
+                            let Some(repo) = queue.lock().expect("loc queue lock poisoned").next() else {
+                                break;
+                            };
+                            let cached = cache.lock().expect("loc cache lock poisoned").get(&repo.id).cloned();
+                            let fresh = match &cached {
+                                Some(entry) if entry.head_oid.is_some() && entry.head_oid == repo.head_oid => entry.clone(),
+                                _ => {
+                                    let (repo_add, repo_del, commits, repo_truncated) = self.repo_loc(username, &repo.name, commit_cap)?;
+                                    let entry = LocCacheEntry {
+                                        head_oid: repo.head_oid.clone(),
+                                        additions: repo_add,
+                                        deletions: repo_del,
+                                        commits,
+                                        truncated: repo_truncated,
+                                    };
+                                    let mut cache = cache.lock().expect("loc cache lock poisoned");
+                                    cache.insert(repo.id.clone(), entry.clone());
+                                    save_cache(&self.cache_dir, &cache);
+                                    entry
+                                }
+                            };
+                            add += fresh.additions;
+                            del += fresh.deletions;
+                            truncated |= fresh.truncated;
+                        }
+                        Ok((add, del, truncated))
+                    })
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(|handle| handle.join().expect("loc worker thread panicked"))
+                .collect::<Vec<_>>()
+        });
+
+        let mut total_add = 0u64;
+        let mut total_del = 0u64;
+        let mut truncated = false;
+        for result in results {
+            let (add, del, repo_truncated) = result?;
+            total_add += add;
+            total_del += del;
+            truncated |= repo_truncated;
+        }
+        Ok((total_add, total_del, truncated))
+    }
+
+    /// The [`TOP_REPOS_FOR_STAR_HISTORY`] owned repositories with the most
+    /// stars, most-starred first — the "top repos" [`Self::star_history`]
+    /// pages stargazers for, since walking every owned repo's full
+    /// stargazer list would be far too expensive for a card render.
+    fn top_starred_repos(&self, username: &str) -> Result<Vec<OwnedRepo>> {
+        let data = self.query(TOP_REPOS_BY_STARS_QUERY, json!({ "login": username }))?;
+        let nodes = data["data"]["user"]["repositories"]["nodes"].as_array().cloned().unwrap_or_default();
+        let mut repos = nodes
+            .into_iter()
+            .map(|r| {
+                let stars = r["stargazers"]["totalCount"].as_u64().unwrap_or(0) as u32;
+                let repo = OwnedRepo { id: r["id"].as_str().unwrap_or_default().to_string(), name: r["name"].as_str().unwrap_or_default().to_string(), head_oid: None };
+                (repo, stars)
+            })
+            .collect::<Vec<_>>();
+        repos.sort_by(|a, b| b.1.cmp(&a.1));
+        repos.truncate(TOP_REPOS_FOR_STAR_HISTORY);
+        Ok(repos.into_iter().map(|(repo, _)| repo).collect())
+    }
+
+    /// Dates `repo`'s stargazers starred it, oldest first, paged
+    /// [`STARGAZER_PAGE_LIMIT`] pages deep (100 per page) before giving up —
+    /// an honest cap rather than an unbounded walk, since a popular repo can
+    /// have far more stargazers than a card render can afford to page
+    /// through.
+    fn repo_stargazer_dates(&self, owner: &str, repo: &str) -> Result<Vec<chrono::NaiveDate>> {
+        let mut dates = Vec::new();
+        let mut cursor: Option<String> = None;
+        for _ in 0..STARGAZER_PAGE_LIMIT {
+            self.check_cancelled()?;
+            let data = self.query(STARGAZERS_PAGE_QUERY, json!({ "owner": owner, "name": repo, "after": cursor }))?;
+            let stargazers = &data["data"]["repository"]["stargazers"];
+            for edge in stargazers["edges"].as_array().cloned().unwrap_or_default() {
+                if let Some(date) = edge["starredAt"].as_str().and_then(parse_rfc3339) {
+                    dates.push(date.date_naive());
+                }
+            }
+            let page_info = &stargazers["pageInfo"];
+            if !page_info["hasNextPage"].as_bool().unwrap_or(false) {
+                break;
+            }
+            cursor = page_info["endCursor"].as_str().map(str::to_string);
+        }
+        Ok(dates)
+    }
+
+    /// Cumulative stars-over-time for `username`'s most-starred repos,
+    /// combined into one timeline, one point per day stars were gained.
+    /// Caches each repo's raw stargazer dates on disk by [`OwnedRepo::id`],
+    /// like [`Self::total_loc`], so repeat runs don't re-page them. Unlike
+    /// the LOC cache, a cached entry is never invalidated: stars a repo gains
+    /// after its first fetch don't appear while its entry is cached. Opt-in — see `Config::show_star_history` — since paging
+    /// stargazers is the most expensive collector this client runs.
+    #[tracing::instrument(skip(self))]
+    pub fn star_history(&self, username: &str) -> Result<Vec<StarHistoryPoint>> {
+        let mut cache = load_stargazer_cache(&self.cache_dir);
+        let repos = self.top_starred_repos(username)?;
+        let mut dates = Vec::new();
+        for repo in repos {
+            self.check_cancelled()?;
+            let repo_dates = match cache.get(&repo.id) {
+                Some(cached) => cached.clone(),
+                None => {
+                    let fetched = self.repo_stargazer_dates(username, &repo.name)?;
+                    cache.insert(repo.id.clone(), fetched.clone());
+                    fetched
+                }
+            };
+            dates.extend(repo_dates);
+        }
+        save_stargazer_cache(&self.cache_dir, &cache);
+        Ok(cumulative_by_day(dates))
+    }
+
+    /// The user's most-starred owned repository, for the optional
+    /// "Spotlight" box. `None` if the user owns no repositories. Uses the
+    /// same top-repos query as [`Self::top_starred_repos`], with
+    /// `description`/`primaryLanguage` added for display.
+    pub fn spotlight_repo(&self, username: &str) -> Result<Option<SpotlightRepo>> {
+        let data = self.query(SPOTLIGHT_REPO_QUERY, json!({ "login": username }))?;
+        let nodes = data["data"]["user"]["repositories"]["nodes"].as_array().cloned().unwrap_or_default();
+        Ok(nodes
+            .into_iter()
+            .map(|r| SpotlightRepo {
+                name: r["name"].as_str().unwrap_or_default().to_string(),
+                description: r["description"].as_str().map(str::to_string),
+                stars: r["stargazers"]["totalCount"].as_u64().unwrap_or(0) as u32,
+                language: r["primaryLanguage"]["name"].as_str().map(str::to_string),
+            })
+            .max_by_key(|repo| repo.stars))
+    }
+
+    /// The most recently pushed-to owned repos, most recent first, for the
+    /// "Now hacking on" stat. Always fetched fresh, like
+    /// [`Self::contribution_mix`] — unlike [`Self::star_history`]'s
+    /// stargazer walk, this is a single cheap query, so there's no opt-in
+    /// flag to gate it.
+    pub fn currently_working_on(&self, username: &str) -> Result<Vec<String>> {
+        let data = self.query(CURRENTLY_WORKING_ON_QUERY, json!({ "login": username }))?;
+        let nodes = data["data"]["user"]["repositories"]["nodes"].as_array().cloned().unwrap_or_default();
+        Ok(nodes
+            .into_iter()
+            .filter_map(|r| r["name"].as_str().map(str::to_string))
+            .take(CURRENTLY_WORKING_ON_LIMIT)
+            .collect())
+    }
+
+    /// Open-issue counts by label (bug/enhancement/help-wanted) for each
+    /// `owner/repo` in `repos`, for the optional "Maintainer dashboard"
+    /// section. `repos` comes straight from `Config::maintained_repos`
+    /// (empty by default, which omits the section), so unlike the other
+    /// derived stats this one's presence is config-gated rather than a
+    /// dedicated `show_*` flag — see [`crate::maintainer`].
+    pub fn maintainer_dashboard(&self, repos: &[String]) -> Result<Vec<MaintainedRepoLabels>> {
+        repos
+            .iter()
+            .map(|repo| {
+                let (owner, name) = repo.split_once('/').ok_or_else(|| Error::Graphql(format!("{repo} is not OWNER/REPO")))?;
+                let data = self.query(MAINTAINER_DASHBOARD_QUERY, json!({ "owner": owner, "name": name }))?;
+                let repository = &data["data"]["repository"];
+                if repository.is_null() {
+                    return Err(Error::Graphql(format!("no repository {repo}")));
+                }
+                Ok(MaintainedRepoLabels {
+                    repo: repo.clone(),
+                    bugs: repository["bugs"]["totalCount"].as_u64().unwrap_or(0) as u32,
+                    enhancements: repository["enhancements"]["totalCount"].as_u64().unwrap_or(0) as u32,
+                    help_wanted: repository["helpWanted"]["totalCount"].as_u64().unwrap_or(0) as u32,
+                })
+            })
+            .collect()
+    }
+
+    /// Remaining GraphQL quota for the token, as reported by GitHub itself —
+    /// used by the `/healthz` payload in server mode so an operator can see
+    /// quota exhaustion coming before it starts failing requests.
+    pub fn rate_limit(&self) -> Result<RateLimit> {
+        let data = self.query(RATE_LIMIT_QUERY, json!({}))?;
+        let limit = &data["data"]["rateLimit"];
+        Ok(RateLimit {
+            limit: limit["limit"].as_u64().unwrap_or(0) as u32,
+            remaining: limit["remaining"].as_u64().unwrap_or(0) as u32,
+            reset_at: limit["resetAt"].as_str().unwrap_or_default().to_string(),
+        })
+    }
+
+    /// Day-by-day contribution counts for the last year, as shown on a
+    /// GitHub profile's calendar. This is the only granularity GitHub's
+    /// GraphQL API exposes for date-bucketed contributions — it's already a
+    /// combined total of commits, issues, PRs, and reviews, with no way to
+    /// ask for commits alone.
+    #[tracing::instrument(skip(self))]
+    pub fn contribution_calendar(&self, username: &str) -> Result<Vec<ContributionDay>> {
+        let data = self.query(CONTRIBUTION_CALENDAR_QUERY, json!({ "login": username }))?;
+        let weeks = data["data"]["user"]["contributionsCollection"]["contributionCalendar"]["weeks"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default();
+        let mut days = Vec::new();
+        for week in weeks {
+            for day in week["contributionDays"].as_array().cloned().unwrap_or_default() {
+                let Some(date) = day["date"].as_str().and_then(|s| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()) else {
+                    continue;
+                };
+                let count = day["contributionCount"].as_u64().unwrap_or(0) as u32;
+                days.push(ContributionDay { date, count });
+            }
+        }
+        Ok(days)
+    }
+
+    /// Total contributions per calendar year, from account creation through
+    /// the current year, for the optional "Contribution history" table.
+    /// `contributionsCollection` only accepts a single `from`/`to` range, so
+    /// this costs one query per year rather than one for the whole span.
+    /// Empty if the user's creation date can't be read.
+    #[tracing::instrument(skip(self))]
+    pub fn contribution_history(&self, username: &str) -> Result<Vec<YearlyContributions>> {
+        Ok(self
+            .yearly_totals(username, CONTRIBUTIONS_BY_YEAR_QUERY, |data| {
+                data["data"]["user"]["contributionsCollection"]["contributionCalendar"]["totalContributions"].as_u64().unwrap_or(0) as u32
+            })?
+            .into_iter()
+            .map(|(year, total)| YearlyContributions { year, total })
+            .collect())
+    }
+
+    /// All-time commit count, from account creation through the current
+    /// year, unlike [`Self::commit_count`] which only sees the current year
+    /// — `contributionsCollection` defaults to a one-year window when no
+    /// `from`/`to` is given. Costs one query per year, same tradeoff as
+    /// [`Self::contribution_history`].
+    #[tracing::instrument(skip(self))]
+    pub fn commit_count_all_time(&self, username: &str) -> Result<u32> {
+        Ok(self
+            .yearly_totals(username, COMMITS_BY_YEAR_QUERY, |data| {
+                data["data"]["user"]["contributionsCollection"]["totalCommitContributions"].as_u64().unwrap_or(0) as u32
+            })?
+            .into_iter()
+            .map(|(_year, count)| count)
+            .sum())
+    }
+
+    /// Shared by [`Self::contribution_history`] and
+    /// [`Self::commit_count_all_time`]: walks every calendar year from
+    /// account creation through the current year, running `query` (a
+    /// `$login`/`$from`/`$to` query) against each and reading one number out
+    /// of the response with `extract`. Empty if the user's creation date
+    /// can't be read.
+    fn yearly_totals(&self, username: &str, query: &str, extract: impl Fn(&Value) -> u32) -> Result<Vec<(i32, u32)>> {
+        use chrono::Datelike;
+
+        let data = self.query(USER_CREATED_AT_QUERY, json!({ "login": username }))?;
+        let Some(created_at) = data["data"]["user"]["createdAt"].as_str().and_then(parse_rfc3339) else {
+            return Ok(Vec::new());
+        };
+
+        let mut years = Vec::new();
+        for year in created_at.year()..=chrono::Utc::now().year() {
+            self.check_cancelled()?;
+            let from = format!("{year}-01-01T00:00:00Z");
+            let to = format!("{year}-12-31T23:59:59Z");
+            let data = self.query(query, json!({ "login": username, "from": from, "to": to }))?;
+            years.push((year, extract(&data)));
+        }
+        Ok(years)
+    }
+
+    /// OAuth scopes granted to this token, as reported by the `X-OAuth-Scopes`
+    /// header on any authenticated REST response — used by `halfguru doctor`
+    /// to check the token can actually do what halfguru needs. Also proves
+    /// the token is valid and the API is reachable, since a bad token or a
+    /// network problem fails this before scopes are ever read.
+    pub fn token_scopes(&self) -> Result<Vec<String>> {
+        let resp = self
+            .http
+            .get(USER_ENDPOINT)
+            .header(AUTHORIZATION, format!("bearer {}", self.token))
+            .header(USER_AGENT, "halfguru")
+            .send()?
+            .error_for_status()?;
+        let scopes = resp.headers().get("x-oauth-scopes").and_then(|v| v.to_str().ok()).unwrap_or("");
+        Ok(scopes.split(',').map(str::trim).filter(|s| !s.is_empty()).map(str::to_string).collect())
+    }
+
+    /// The directory the LOC cache and (with dev cache enabled) raw GraphQL
+    /// responses are written under — see [`Self::with_cache_dir`].
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+}
+
+/// Remaining GraphQL quota for the token, as returned by [`GithubClient::rate_limit`].
+#[derive(Debug, Clone, Serialize)]
+pub struct RateLimit {
+    pub limit: u32,
+    pub remaining: u32,
+    pub reset_at: String,
+}
+
+/// A repository owned by the scanned user, as returned by [`GithubClient::list_owned_repos`].
+/// `id` is the stable GraphQL node ID; `name` may change across renames/transfers.
+pub struct OwnedRepo {
+    pub id: String,
+    pub name: String,
+    /// Default branch HEAD commit, `None` for an empty repo with no default
+    /// branch. Used by [`GithubClient::total_loc`] to tell whether a cached
+    /// LOC entry is still current.
+    pub head_oid: Option<String>,
+}
+
+/// One day's worth of contribution activity, as returned by
+/// [`GithubClient::contribution_calendar`].
+#[derive(Debug, Clone, Copy)]
+pub struct ContributionDay {
+    pub date: chrono::NaiveDate,
+    pub count: u32,
+}
+
+/// One calendar year's total contributions, as returned by
+/// [`GithubClient::contribution_history`] for the optional "Contribution
+/// history" table.
+#[derive(Debug, Clone, Copy)]
+pub struct YearlyContributions {
+    pub year: i32,
+    pub total: u32,
+}
+
+/// Breakdown of `contributionsCollection` totals by contribution type, as
+/// returned by [`GithubClient::contribution_mix`] — a quick read on whether
+/// someone is mostly a committer, reviewer, or issue triager.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ContributionMix {
+    pub commits: u32,
+    pub pull_requests: u32,
+    pub issues: u32,
+    pub reviews: u32,
+    /// Commits to repositories the user has marked private, invisible to
+    /// anyone but themselves. Folded into `commits` or shown alongside it
+    /// depending on [`crate::svg::PrivateContributionsMode`]; excluded from
+    /// `commits` itself so the default rendering matches what everyone else
+    /// already sees on GitHub.
+    pub restricted_commits: u32,
+}
+
+/// Commit contributions split by repository owner type, as returned by
+/// [`GithubClient::commits_by_owner_type`] — how much work lands on the
+/// user's own repos versus an org's versus someone else's.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CommitOwnershipSplit {
+    pub own: u32,
+    pub org: u32,
+    pub other: u32,
+}
+
+impl ContributionMix {
+    pub fn total(&self) -> u32 {
+        self.commits + self.pull_requests + self.issues + self.reviews
+    }
+}
+
+/// One point on the cumulative "Stars over time" chart, as returned by
+/// [`GithubClient::star_history`]: the running total of stars as of `date`,
+/// across every day at least one star was gained.
+#[derive(Debug, Clone, Copy)]
+pub struct StarHistoryPoint {
+    pub date: chrono::NaiveDate,
+    pub cumulative: u32,
+}
+
+/// Most repos to page stargazers for in [`GithubClient::star_history`].
+const TOP_REPOS_FOR_STAR_HISTORY: usize = 5;
+
+/// Most 100-stargazer pages [`GithubClient::repo_stargazer_dates`] will walk
+/// per repo before giving up.
+const STARGAZER_PAGE_LIMIT: usize = 10;
+
+/// Most 100-repo pages [`GithubClient::list_owned_repos`] and
+/// [`GithubClient::star_count`] will walk before giving up — an honest cap
+/// rather than an unbounded walk, like [`STARGAZER_PAGE_LIMIT`], so an
+/// account with an enormous number of repos can't page forever.
+const OWNED_REPOS_PAGE_LIMIT: usize = 20;
+
+/// Repos [`GithubClient::total_loc`] walks concurrently.
+const LOC_CONCURRENCY: usize = 4;
+
+/// Languages requested per repo in [`GithubClient::language_totals`] — GitHub
+/// orders a repo's `languages` connection by size descending, so this is
+/// really "smaller languages a repo has that never move the byte totals
+/// enough to matter" being cut off, not a meaningful loss of data.
+const LANGUAGES_PER_REPO_LIMIT: u32 = 10;
+
+/// Repos [`GithubClient::currently_working_on`] shows in the "Now hacking
+/// on" stat.
+const CURRENTLY_WORKING_ON_LIMIT: usize = 2;
+
+/// Most pages [`GithubClient::repo_loc`] will walk per repo before giving
+/// up, independent of any caller-supplied `commit_cap`. A "page" shrinks
+/// under [`is_page_too_heavy`] retries, so this bounds page count, not
+/// commit count.
+const COMMIT_HISTORY_PAGE_LIMIT: usize = 500;
+
+/// Commits per page [`GithubClient::repo_loc`] starts out requesting.
+const COMMIT_HISTORY_PAGE_SIZE: u32 = 100;
+
+/// Smallest page [`GithubClient::repo_loc`] will downshift to before giving
+/// up and surfacing the error instead of continuing to retry.
+const COMMIT_HISTORY_MIN_PAGE_SIZE: u32 = 5;
+
+/// Whether `error` looks like GitHub gave up on a history page for being too
+/// expensive to compute — a request timeout, or the generic `"something
+/// went wrong"` message GitHub's GraphQL API returns for queries that time
+/// out server-side rather than failing normally — rather than some other
+/// GraphQL error a smaller page wouldn't fix (a bad argument, a missing
+/// field, an auth problem).
+fn is_page_too_heavy(error: &Error) -> bool {
+    match error {
+        Error::Http(e) => e.is_timeout(),
+        Error::Graphql(msg) => msg.to_lowercase().contains("something went wrong"),
+        _ => false,
+    }
+}
+
+/// Sorts `dates`, then collapses same-day entries into one [`StarHistoryPoint`]
+/// per distinct day with a running total.
+fn cumulative_by_day(mut dates: Vec<chrono::NaiveDate>) -> Vec<StarHistoryPoint> {
+    dates.sort();
+    let mut points = Vec::new();
+    let mut cumulative = 0u32;
+    for (date, count) in dates.into_iter().fold(Vec::<(chrono::NaiveDate, u32)>::new(), |mut acc, date| {
+        match acc.last_mut() {
+            Some((last_date, count)) if *last_date == date => *count += 1,
+            _ => acc.push((date, 1)),
+        }
+        acc
+    }) {
+        cumulative += count;
+        points.push(StarHistoryPoint { date, cumulative });
+    }
+    points
+}
+
+/// The user's most-starred owned repository, as returned by
+/// [`GithubClient::spotlight_repo`] for the optional "Spotlight" box.
+#[derive(Debug, Clone)]
+pub struct SpotlightRepo {
+    pub name: String,
+    pub description: Option<String>,
+    pub stars: u32,
+    pub language: Option<String>,
+}
+
+/// Open-issue counts by label for one `Config::maintained_repos` entry, as
+/// returned by [`GithubClient::maintainer_dashboard`] for the optional
+/// "Maintainer dashboard" section.
+#[derive(Debug, Clone)]
+pub struct MaintainedRepoLabels {
+    pub repo: String,
+    pub bugs: u32,
+    pub enhancements: u32,
+    pub help_wanted: u32,
+}
+
+/// Stats shown on a `repo-card` (see [`GithubClient::repo_info`] and `repo_card.rs`).
+pub struct RepoInfo {
+    pub name: String,
+    pub stars: u32,
+    pub forks: u32,
+    pub open_issues: u32,
+    pub primary_language: Option<String>,
+    pub latest_release: Option<String>,
+}
+
+fn parse_rfc3339(s: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::parse_from_rfc3339(s).ok().map(|dt| dt.with_timezone(&chrono::Utc))
+}
+
+/// The middle value of `values` once sorted, averaging the two middle values
+/// on an even-length input. `None` for an empty sample.
+fn median(mut values: Vec<f64>) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mid = values.len() / 2;
+    Some(if values.len() % 2 == 0 { (values[mid - 1] + values[mid]) / 2.0 } else { values[mid] })
+}
+
+fn load_cache(cache_dir: &Path) -> HashMap<String, LocCacheEntry> {
+    let Some(raw) = fs::read_to_string(cache_dir.join(CACHE_FILE)).ok() else {
+        return HashMap::new();
+    };
+    migrate_cache(serde_json::from_str(&raw).ok())
+}
+
+/// Upgrades a parsed [`CacheEnvelope`] to [`CACHE_VERSION`], discarding
+/// entries if the envelope failed to parse (a corrupted file, or entries
+/// missing the `truncated` flag version 2 added, which has no default) or
+/// carries a version newer than [`CACHE_VERSION`]. Version 3's
+/// `head_oid`/`commits` default when absent, so a version-2 file still
+/// loads, with every `head_oid` `None` and thus re-walked on next use
+/// (see [`LocCacheEntry`]). A discarded cache just
+/// means the next run re-walks every repo from scratch, not a correctness
+/// problem.
+fn migrate_cache(envelope: Option<CacheEnvelope>) -> HashMap<String, LocCacheEntry> {
+    match envelope {
+        Some(envelope) if envelope.version <= CACHE_VERSION => envelope.entries,
+        _ => HashMap::new(),
+    }
+}
+
+fn save_cache(cache_dir: &Path, cache: &HashMap<String, LocCacheEntry>) {
+    let _ = fs::create_dir_all(cache_dir);
+    let envelope = CacheEnvelope { version: CACHE_VERSION, entries: cache.clone() };
+    if let Ok(s) = serde_json::to_string_pretty(&envelope) {
+        let _ = fs::write(cache_dir.join(CACHE_FILE), s);
+    }
+}
+
+fn load_stargazer_cache(cache_dir: &Path) -> HashMap<String, Vec<chrono::NaiveDate>> {
+    let Some(raw) = fs::read_to_string(cache_dir.join(STARGAZER_CACHE_FILE)).ok() else {
+        return HashMap::new();
+    };
+    let entries = match serde_json::from_str::<StargazerCacheEnvelope>(&raw) {
+        Ok(envelope) if envelope.version <= CACHE_VERSION => envelope.entries,
+        _ => return HashMap::new(),
+    };
+    entries
+        .into_iter()
+        .map(|(id, dates)| {
+            let dates = dates.iter().filter_map(|s| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()).collect();
+            (id, dates)
+        })
+        .collect()
+}
+
+fn save_stargazer_cache(cache_dir: &Path, cache: &HashMap<String, Vec<chrono::NaiveDate>>) {
+    let _ = fs::create_dir_all(cache_dir);
+    let entries = cache.iter().map(|(id, dates)| (id.clone(), dates.iter().map(|d| d.format("%Y-%m-%d").to_string()).collect())).collect();
+    let envelope = StargazerCacheEnvelope { version: CACHE_VERSION, entries };
+    if let Ok(s) = serde_json::to_string_pretty(&envelope) {
+        let _ = fs::write(cache_dir.join(STARGAZER_CACHE_FILE), s);
+    }
+}
+
+fn dev_cache_path(cache_dir: &Path, query: &str, variables: &Value) -> std::path::PathBuf {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    query.hash(&mut hasher);
+    variables.to_string().hash(&mut hasher);
+    cache_dir.join(DEV_CACHE_SUBDIR).join(format!("{:x}.json", hasher.finish()))
+}
+
+fn load_dev_cache(cache_dir: &Path, query: &str, variables: &Value) -> Option<Value> {
+    let raw = fs::read_to_string(dev_cache_path(cache_dir, query, variables)).ok()?;
+    serde_json::from_str(&raw).ok()
+}
+
+fn save_dev_cache(cache_dir: &Path, query: &str, variables: &Value, response: &Value) {
+    let path = dev_cache_path(cache_dir, query, variables);
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    if let Ok(s) = serde_json::to_string_pretty(response) {
+        let _ = fs::write(path, s);
+    }
+}
+
+const USER_EXISTS_QUERY: &str = r#"
+query($login: String!) {
+  user(login: $login) { id }
+}
+"#;
+
+const STAR_COUNT_QUERY: &str = r#"
+query($login: String!, $after: String) {
+  user(login: $login) {
+    repositories(first: 100, after: $after, ownerAffiliations: OWNER) {
+      pageInfo { hasNextPage endCursor }
+      nodes { stargazers { totalCount } }
+    }
+  }
+}
+"#;
+
+const COMMIT_COUNT_QUERY: &str = r#"
+query($login: String!) {
+  user(login: $login) {
+    contributionsCollection { totalCommitContributions }
+  }
+}
+"#;
+
+const CONTRIBUTION_MIX_QUERY: &str = r#"
+query($login: String!) {
+  user(login: $login) {
+    contributionsCollection {
+      totalCommitContributions
+      totalPullRequestContributions
+      totalIssueContributions
+      totalPullRequestReviewContributions
+      restrictedContributionsCount
+    }
+  }
+}
+"#;
+
+const COMMIT_OWNERSHIP_QUERY: &str = r#"
+query($login: String!) {
+  user(login: $login) {
+    contributionsCollection {
+      commitContributionsByRepository(maxRepositories: 100) {
+        contributions { totalCount }
+        repository {
+          owner { login __typename }
+        }
+      }
+    }
+  }
+}
+"#;
+
+const FOLLOWER_COUNT_QUERY: &str = r#"
+query($login: String!) {
+  user(login: $login) {
+    followers { totalCount }
+  }
+}
+"#;
+
+const AVATAR_URL_QUERY: &str = r#"
+query($login: String!) {
+  user(login: $login) {
+    avatarUrl(size: 200)
+  }
+}
+"#;
+
+const OWNED_REPOS_QUERY: &str = r#"
+query($login: String!, $after: String) {
+  user(login: $login) {
+    repositories(first: 100, after: $after, ownerAffiliations: OWNER) {
+      pageInfo { hasNextPage endCursor }
+      nodes { id name defaultBranchRef { target { oid } } }
+    }
+  }
+}
+"#;
+
+const LANGUAGE_TOTALS_QUERY: &str = r#"
+query($login: String!, $after: String, $languagesPerRepo: Int!) {
+  user(login: $login) {
+    repositories(first: 100, after: $after, ownerAffiliations: OWNER, isFork: false) {
+      pageInfo { hasNextPage endCursor }
+      nodes {
+        languages(first: $languagesPerRepo, orderBy: { field: SIZE, direction: DESC }) {
+          edges { size node { name } }
+        }
+      }
+    }
+  }
+}
+"#;
+
+const TOP_REPOS_BY_STARS_QUERY: &str = r#"
+query($login: String!) {
+  user(login: $login) {
+    repositories(first: 100, ownerAffiliations: OWNER) {
+      nodes { id name stargazers { totalCount } }
+    }
+  }
+}
+"#;
+
+const STARGAZERS_PAGE_QUERY: &str = r#"
+query($owner: String!, $name: String!, $after: String) {
+  repository(owner: $owner, name: $name) {
+    stargazers(first: 100, after: $after, orderBy: { field: STARRED_AT, direction: ASC }) {
+      pageInfo { hasNextPage endCursor }
+      edges { starredAt }
+    }
+  }
+}
+"#;
+
+const SPOTLIGHT_REPO_QUERY: &str = r#"
+query($login: String!) {
+  user(login: $login) {
+    repositories(first: 100, ownerAffiliations: OWNER) {
+      nodes { name description primaryLanguage { name } stargazers { totalCount } }
+    }
+  }
+}
+"#;
+
+const CURRENTLY_WORKING_ON_QUERY: &str = r#"
+query($login: String!) {
+  user(login: $login) {
+    repositories(first: 5, ownerAffiliations: OWNER, orderBy: { field: PUSHED_AT, direction: DESC }) {
+      nodes { name }
+    }
+  }
+}
+"#;
+
+const REPO_COUNT_QUERY: &str = r#"
+query($login: String!) {
+  user(login: $login) {
+    repositories(ownerAffiliations: OWNER) { totalCount }
+  }
+}
+"#;
+
+const REPO_INFO_QUERY: &str = r#"
+query($owner: String!, $name: String!) {
+  repository(owner: $owner, name: $name) {
+    stargazerCount
+    forkCount
+    primaryLanguage { name }
+    issues(states: OPEN) { totalCount }
+    releases(last: 1) { nodes { tagName } }
+  }
+}
+"#;
+
+const MAINTAINER_DASHBOARD_QUERY: &str = r#"
+query($owner: String!, $name: String!) {
+  repository(owner: $owner, name: $name) {
+    bugs: issues(states: OPEN, labels: ["bug"]) { totalCount }
+    enhancements: issues(states: OPEN, labels: ["enhancement"]) { totalCount }
+    helpWanted: issues(states: OPEN, labels: ["help wanted"]) { totalCount }
+  }
+}
+"#;
+
+const RATE_LIMIT_QUERY: &str = r#"
+query {
+  rateLimit { limit remaining resetAt }
+}
+"#;
+
+const CONTRIBUTION_CALENDAR_QUERY: &str = r#"
+query($login: String!) {
+  user(login: $login) {
+    contributionsCollection {
+      contributionCalendar {
+        weeks { contributionDays { date contributionCount } }
+      }
+    }
+  }
+}
+"#;
+
+const USER_CREATED_AT_QUERY: &str = r#"
+query($login: String!) {
+  user(login: $login) { createdAt }
+}
+"#;
+
+const CONTRIBUTIONS_BY_YEAR_QUERY: &str = r#"
+query($login: String!, $from: DateTime!, $to: DateTime!) {
+  user(login: $login) {
+    contributionsCollection(from: $from, to: $to) {
+      contributionCalendar { totalContributions }
+    }
+  }
+}
+"#;
+
+const COMMITS_BY_YEAR_QUERY: &str = r#"
+query($login: String!, $from: DateTime!, $to: DateTime!) {
+  user(login: $login) {
+    contributionsCollection(from: $from, to: $to) { totalCommitContributions }
+  }
+}
+"#;
+
+const ISSUE_RESPONSE_QUERY: &str = r#"
+query($owner: String!, $name: String!) {
+  repository(owner: $owner, name: $name) {
+    issues(first: 50, orderBy: { field: CREATED_AT, direction: DESC }) {
+      nodes {
+        createdAt
+        comments(first: 1) { nodes { createdAt } }
+      }
+    }
+  }
+}
+"#;
+
+const REPO_HISTORY_QUERY: &str = r#"
+query($owner: String!, $name: String!, $author: String!) {
+  repository(owner: $owner, name: $name) {
+    defaultBranchRef {
+      target {
+        ... on Commit {
+          history(author: { id: $author }) {
+            edges { node { additions deletions message committedDate } }
+          }
+        }
+      }
+    }
+  }
+}
+"#;
+
+/// Lighter than [`REPO_HISTORY_QUERY`] — just the fields [`GithubClient::repo_loc`]
+/// needs to sum LOC a page at a time, plus pagination info.
+const REPO_COMMIT_LOC_PAGE_QUERY: &str = r#"
+query($owner: String!, $name: String!, $author: String!, $after: String, $first: Int!) {
+  repository(owner: $owner, name: $name) {
+    defaultBranchRef {
+      target {
+        ... on Commit {
+          history(author: { id: $author }, first: $first, after: $after) {
+            edges { node { additions deletions } }
+            pageInfo { hasNextPage endCursor }
+          }
+        }
+      }
+    }
+  }
+}
+"#;
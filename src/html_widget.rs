@@ -0,0 +1,57 @@
+//! `--format html`: a self-contained HTML document embedding both the light
+//! and dark card SVGs inline plus a small toggle button, for personal
+//! websites that would rather `<iframe src="card.html">` one file than wire
+//! up their own `prefers-color-scheme` handling around a bare `.svg`.
+//!
+//! Both SVGs are inlined directly into the document (not referenced via
+//! `<img src>`) so the toggle can just flip which one is visible — no second
+//! request, no flash of the other theme while it loads.
+
+/// Assembles the widget from already-rendered light/dark SVGs — callers
+/// render both themselves (see [`crate::render::SvgRenderer::render_multi`])
+/// since they usually need the pair anyway (e.g. for `--dual-theme`).
+pub fn build_from(light_svg: &str, dark_svg: &str) -> String {
+    format!(
+        r#"<!doctype html>
+<html>
+<head>
+<meta charset="utf-8">
+<style>
+  body {{ margin: 0; }}
+  .halfguru-widget {{ position: relative; display: inline-block; }}
+  .halfguru-widget[data-theme="dark"] .halfguru-light {{ display: none; }}
+  .halfguru-widget:not([data-theme="dark"]) .halfguru-dark {{ display: none; }}
+  .halfguru-toggle {{
+    position: absolute; top: 6px; right: 6px; border: none; border-radius: 4px;
+    background: rgba(128, 128, 128, 0.25); color: inherit; cursor: pointer;
+    font: 400 11px sans-serif; padding: 2px 6px;
+  }}
+</style>
+</head>
+<body>
+<div class="halfguru-widget" data-theme="light">
+  <div class="halfguru-light">{light_svg}</div>
+  <div class="halfguru-dark">{dark_svg}</div>
+  <button class="halfguru-toggle" onclick="halfguruToggleTheme()">&#9788;</button>
+</div>
+<script>
+function halfguruToggleTheme() {{
+  var widget = document.querySelector(".halfguru-widget");
+  var next = widget.dataset.theme === "dark" ? "light" : "dark";
+  widget.dataset.theme = next;
+  try {{ localStorage.setItem("halfguru-theme", next); }} catch (e) {{}}
+}}
+(function () {{
+  var widget = document.querySelector(".halfguru-widget");
+  var saved;
+  try {{ saved = localStorage.getItem("halfguru-theme"); }} catch (e) {{}}
+  if (saved === "dark" || (!saved && window.matchMedia && window.matchMedia("(prefers-color-scheme: dark)").matches)) {{
+    widget.dataset.theme = "dark";
+  }}
+}})();
+</script>
+</body>
+</html>
+"#
+    )
+}
@@ -0,0 +1,37 @@
+//! Splices a rendered card into an existing file (typically README.md)
+//! between `<!-- halfguru:start -->`/`<!-- halfguru:end -->` markers, so the
+//! `inject` subcommand can keep a README's embed current without the user
+//! hand-maintaining the snippet the way [`crate::picture::snippet`] still
+//! expects them to for `--dual-theme`.
+
+use crate::error::{Error, Result};
+
+const START_MARKER: &str = "<!-- halfguru:start -->";
+const END_MARKER: &str = "<!-- halfguru:end -->";
+
+/// Replaces everything between [`START_MARKER`] and [`END_MARKER`] in
+/// `readme` with `embed`, keeping the markers themselves so a later run can
+/// find the block again. Fails if either marker is missing rather than
+/// appending a second copy, since a typo'd marker would otherwise leave an
+/// orphaned block behind instead of updating the existing one.
+pub fn inject(readme: &str, embed: &str) -> Result<String> {
+    let start = readme.find(START_MARKER).ok_or_else(|| Error::Other(format!("{START_MARKER} not found in README")))?;
+    let after_start = start + START_MARKER.len();
+    let end = readme[after_start..]
+        .find(END_MARKER)
+        .map(|i| after_start + i)
+        .ok_or_else(|| Error::Other(format!("{END_MARKER} not found in README")))?;
+    Ok(format!("{}\n{embed}\n{}", &readme[..after_start], &readme[end..]))
+}
+
+/// Builds the markup to inject: a plain `<img>` referencing `svg_path` by
+/// default, or the raw `svg_content` inlined directly when `inline` is set —
+/// inlining lets CSS/JS on the embedding page reach into the SVG, at the cost
+/// of duplicating its bytes into the README on every update.
+pub fn embed(svg_path: &str, svg_content: &str, alt: &str, inline: bool) -> String {
+    if inline {
+        svg_content.to_string()
+    } else {
+        format!(r#"<img alt="{alt}" src="{svg_path}">"#)
+    }
+}
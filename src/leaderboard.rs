@@ -0,0 +1,86 @@
+//! Renders a ranked table of commits/stars across several usernames, for
+//! org-wide READMEs (see the `leaderboard` subcommand in `main.rs`).
+
+use crate::github::GithubClient;
+use crate::svg::Theme;
+use std::thread;
+
+/// One row of the leaderboard: a fetched username's commit/star totals.
+pub struct LeaderboardEntry {
+    pub username: String,
+    pub commits: u32,
+    pub stars: u32,
+}
+
+/// Fetches commits/stars for every username concurrently — one thread per
+/// user, since each is an independent pair of GraphQL round trips and
+/// `GithubClient` is cheap to clone (it just wraps a pooled `reqwest::Client`).
+pub fn fetch_entries(client: &GithubClient, usernames: &[String]) -> crate::error::Result<Vec<LeaderboardEntry>> {
+    thread::scope(|scope| {
+        usernames
+            .iter()
+            .map(|username| {
+                let client = client.clone();
+                scope.spawn(move || {
+                    let commits = client.commit_count(username)?;
+                    let stars = client.star_count(username)?;
+                    Ok(LeaderboardEntry { username: username.clone(), commits, stars })
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().expect("leaderboard fetch thread panicked"))
+            .collect()
+    })
+}
+
+const ROW_HEIGHT: u32 = 25;
+const HEADER_HEIGHT: u32 = 50;
+const WIDTH: u32 = 360;
+
+/// Renders `entries` (already fetched) as a ranked table, sorted by commits
+/// descending, most-committed first.
+pub fn render_leaderboard(entries: &[LeaderboardEntry], theme: Theme) -> String {
+    let colors = theme.colors();
+    let mut ranked: Vec<&LeaderboardEntry> = entries.iter().collect();
+    ranked.sort_by(|a, b| b.commits.cmp(&a.commits));
+
+    let height = HEADER_HEIGHT + ranked.len() as u32 * ROW_HEIGHT + 20;
+    let rows: String = ranked
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let y = HEADER_HEIGHT + i as u32 * ROW_HEIGHT;
+            format!(
+                r#"<text x="20" y="{y}" class="row">{rank}. {username}</text><text x="{WIDTH}" y="{y}" class="row" text-anchor="end">{commits} commits, {stars} stars</text>"#,
+                rank = i + 1,
+                username = entry.username,
+                commits = entry.commits,
+                stars = entry.stars,
+                y = y - 15,
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n    ");
+
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{WIDTH}" height="{height}" viewBox="0 0 {WIDTH} {height}">
+  <style>
+    .card {{ font: 400 14px 'Segoe UI', Ubuntu, sans-serif; }}
+    .title {{ font: 600 16px 'Segoe UI', Ubuntu, sans-serif; fill: {title}; }}
+    .row {{ fill: {text}; }}
+  </style>
+  <rect x="0.5" y="0.5" rx="4.5" width="{width}" height="{height}" fill="{background}" stroke="{border}"/>
+  <g class="card">
+    <text x="20" y="30" class="title">Leaderboard</text>
+    {rows}
+  </g>
+</svg>"#,
+        width = WIDTH - 1,
+        height = height - 1,
+        title = colors.title,
+        text = colors.text,
+        background = colors.background,
+        border = colors.border,
+    )
+}
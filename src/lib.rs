@@ -0,0 +1,63 @@
+//! `halfguru` as a library: [`github::GithubClient`] talks to the GitHub
+//! GraphQL/REST APIs, [`stats::Stats`] is the aggregated numbers behind a
+//! profile card, and [`svg::generate_svg`] paints them onto an SVG — all
+//! reusable from another tool without pulling in this crate's own CLI.
+//! [`age`] is the small "years since a date" helper the "Uptime" row uses.
+//!
+//! Every other module here (config loading, the `server` daemon, CLI
+//! argument parsing, ...) is public only because the `halfguru` binary
+//! (`main.rs`) is a separate crate that consumes this one and needs access
+//! to all of it — it isn't a documented, stability-committed API the way
+//! `github`/`stats`/`svg`/`age` are.
+
+pub mod afterhours;
+pub mod age;
+pub mod ascii;
+pub mod avatar;
+pub mod cli;
+pub mod collaborators;
+pub mod config;
+pub mod contrast;
+pub mod contribution_history;
+pub mod contribution_mix;
+pub mod csv_export;
+pub mod custom_section;
+pub mod datebucket;
+pub mod debug_dump;
+pub mod doctor;
+pub mod emoji;
+pub mod error;
+pub mod export;
+pub mod github;
+pub mod html_widget;
+pub mod inject;
+pub mod leaderboard;
+pub mod linguist;
+pub mod maintainer;
+pub mod metrics_push;
+pub mod picture;
+pub mod plugins;
+pub mod postprocess;
+pub mod quote;
+pub mod render;
+pub mod repo_card;
+pub mod schema;
+pub mod secrets;
+pub mod self_update;
+pub mod server;
+pub mod skills;
+pub mod spotlight;
+pub mod star_history;
+pub mod stats;
+pub mod status;
+pub mod streak;
+pub mod svg;
+pub mod telegram;
+pub mod telemetry;
+pub mod timeline;
+pub mod top_languages;
+pub mod trophies;
+pub mod verify;
+pub mod visitors;
+pub mod weather;
+pub mod webhook;
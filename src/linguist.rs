@@ -0,0 +1,45 @@
+//! A subset of GitHub Linguist's language -> color mapping
+//! (https://github.com/github-linguist/linguist/blob/main/lib/linguist/languages.yml),
+//! for anywhere a stat colors a language the way github.com does — today the
+//! `repo-card` language bar, the "Spotlight" box, and the "Top Languages"
+//! legend.
+
+/// The languages most likely to show up as someone's primary language.
+/// Anything else falls back to a hash-based color in [`color_for`] rather
+/// than growing this table to cover every language linguist knows about.
+const LANGUAGE_COLORS: &[(&str, &str)] = &[
+    ("Rust", "#dea584"),
+    ("Python", "#3572A5"),
+    ("JavaScript", "#f1e05a"),
+    ("TypeScript", "#3178c6"),
+    ("Go", "#00ADD8"),
+    ("Java", "#b07219"),
+    ("C", "#555555"),
+    ("C++", "#f34b7d"),
+    ("C#", "#178600"),
+    ("Ruby", "#701516"),
+    ("PHP", "#4F5D95"),
+    ("Shell", "#89e051"),
+    ("HTML", "#e34c26"),
+    ("CSS", "#563d7c"),
+    ("Swift", "#F05138"),
+    ("Kotlin", "#A97BFF"),
+];
+
+/// The linguist color for `language`, or a color hashed from its name if
+/// it's not in [`LANGUAGE_COLORS`] — so an unrecognized language still gets
+/// a stable, distinct-looking color instead of falling back to gray.
+pub fn color_for(language: &str) -> String {
+    match LANGUAGE_COLORS.iter().find(|(name, _)| *name == language) {
+        Some((_, color)) => color.to_string(),
+        None => hash_color(language),
+    }
+}
+
+fn hash_color(name: &str) -> String {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    name.hash(&mut hasher);
+    let hash = hasher.finish();
+    format!("#{:06x}", (hash & 0x00ff_ffff) as u32)
+}
@@ -0,0 +1,744 @@
+//! Thin CLI binary over the [`halfguru`] library: argument parsing and
+//! subcommand dispatch live here, while everything that actually talks to
+//! GitHub or paints an SVG lives in the library so other tools can reuse it
+//! without going through this binary at all.
+
+use halfguru::ascii;
+use halfguru::avatar;
+use halfguru::error::{Error, Result};
+use halfguru::github::GithubClient;
+use halfguru::render::{self, Renderer, SvgRenderer};
+use halfguru::stats::Stats;
+use halfguru::svg::{CustomThemeConfig, Theme, ThemeColors};
+use halfguru::webhook::Notifier;
+use halfguru::{
+    cli, config, contrast, csv_export, debug_dump, doctor, export, html_widget, inject, leaderboard, metrics_push, picture, quote, repo_card, schema, secrets, self_update, server, status,
+    svg, telegram, telemetry, verify, weather, webhook,
+};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+const USERNAME: &str = "halfguru";
+/// This build's version, checked against the latest GitHub release by
+/// `self-update`. Bumped by hand at release time until packaging picks this
+/// up from the manifest.
+pub(crate) const VERSION: &str = "0.1.0";
+/// Default for the "Uptime" row's age calculation, overridable with
+/// `--birthday <YYYY-MM-DD>` so forks of this tool don't need a source edit
+/// just to show the right age.
+const BIRTHDAY: &str = "2000-01-01";
+const OUTPUT_PATH: &str = "dist/card.svg";
+const LEADERBOARD_OUTPUT_PATH: &str = "dist/leaderboard.svg";
+const REPO_CARD_OUTPUT_DIR: &str = "dist";
+/// Where `server` mode looks for its config file absent `--config`: the
+/// platform config directory (`%APPDATA%\halfguru\config.json` on Windows,
+/// `~/.config/halfguru/config.json` on Linux, `~/Library/Application
+/// Support/halfguru/config.json` on macOS), or `./halfguru.config.json` if
+/// the platform doesn't expose one.
+fn default_config_path() -> std::path::PathBuf {
+    dirs::config_dir()
+        .map(|dir| dir.join("halfguru").join("config.json"))
+        .unwrap_or_else(|| std::path::PathBuf::from("halfguru.config.json"))
+}
+const DEFAULT_HEALTH_ADDR: &str = "0.0.0.0:8080";
+/// How often `server` mode re-renders the card even without a config change,
+/// so stats (stars, commits, ...) stay fresh.
+const SERVER_TICK: Duration = Duration::from_secs(300);
+
+/// Exit status for `--check` when the regenerated card differs from what's
+/// committed — distinct from a plain error so CI can tell "needs a commit"
+/// apart from "something broke".
+const CHECK_EXIT_STALE: i32 = 2;
+
+/// Any I/O or JSON error: an unreadable or malformed `--theme-file`, but
+/// also any other file that couldn't be read or written. `server` mode falls
+/// back to defaults on a bad `--config` instead of failing.
+const EXIT_CONFIG_ERROR: i32 = 3;
+/// The API rejected the token outright (401/403), as opposed to a
+/// transient server error the retry policy already handles.
+const EXIT_AUTH_ERROR: i32 = 4;
+/// The API cut us off for making too many requests (HTTP 429, or a GraphQL
+/// error mentioning a rate limit).
+const EXIT_RATE_LIMITED: i32 = 5;
+/// A batch run (multiple `--username` cards) had at least one success and
+/// at least one failure, so it's neither a clean `0` nor a full `1`.
+const EXIT_PARTIAL_SUCCESS: i32 = 6;
+
+/// Classifies `error` into one of the exit codes above, falling back to the
+/// generic `1` for anything that doesn't fit one of those specific,
+/// programmatically-actionable cases.
+fn exit_code_for(error: &Error) -> i32 {
+    match error {
+        Error::Http(e) if e.status().is_some_and(|s| s.as_u16() == 401 || s.as_u16() == 403) => EXIT_AUTH_ERROR,
+        Error::Http(e) if e.status().is_some_and(|s| s.as_u16() == 429) => EXIT_RATE_LIMITED,
+        Error::Graphql(msg) if msg.to_lowercase().contains("rate limit") => EXIT_RATE_LIMITED,
+        Error::Json(_) | Error::Io(_) => EXIT_CONFIG_ERROR,
+        _ => 1,
+    }
+}
+
+/// Prints `error` to stderr — as a single JSON object under
+/// `--error-format json`, or plain `Display` text otherwise — then exits
+/// with a code from [`exit_code_for`], so CI wrappers and the GitHub Action
+/// can branch on failure kind without parsing stderr text.
+fn fail(error: &Error) -> ! {
+    let code = exit_code_for(error);
+    if flag_value("--error-format").as_deref() == Some("json") {
+        eprintln!("{}", serde_json::json!({ "error": error.to_string(), "exit_code": code }));
+    } else {
+        eprintln!("error: {error}");
+    }
+    std::process::exit(code);
+}
+
+/// Strips fields that are allowed to change run-to-run (currently none —
+/// this is the seam for e.g. a future embedded generation timestamp) before
+/// `--check` compares old vs. regenerated output.
+fn normalize_for_diff(svg: &str) -> &str {
+    svg
+}
+
+/// Collects every `--username <login>` off argv.
+fn usernames_from_args() -> Vec<String> {
+    let mut usernames = Vec::new();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--username" {
+            if let Some(login) = args.next() {
+                usernames.push(login);
+            }
+        }
+    }
+    usernames
+}
+
+/// `--birthday <YYYY-MM-DD>` if given, else [`BIRTHDAY`].
+fn birthday() -> String {
+    flag_value("--birthday").unwrap_or_else(|| BIRTHDAY.to_string())
+}
+
+/// Reads the value following the first occurrence of `flag` off argv.
+fn flag_value(flag: &str) -> Option<String> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == flag {
+            return args.next();
+        }
+    }
+    None
+}
+
+fn flag_present(flag: &str) -> bool {
+    std::env::args().any(|arg| arg == flag)
+}
+
+/// Builds a [`quote::QuoteConfig`] from `--quotes-file` (one quote per
+/// non-blank, non-`#`-comment line, like `secrets.rs`'s `.env` parsing) and
+/// `--quote-daily-seed`. `None` if `--quotes-file` wasn't given, so the
+/// "Quote" row is omitted entirely rather than shown empty.
+fn quote_config_from_flags() -> Option<quote::QuoteConfig> {
+    let path = flag_value("--quotes-file")?;
+    let quotes = std::fs::read_to_string(path)
+        .unwrap_or_default()
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect();
+    Some(quote::QuoteConfig { quotes, daily_seed: flag_present("--quote-daily-seed") })
+}
+
+/// Builds a [`weather::WeatherConfig`] from `--weather-lat`/`--weather-lon`.
+/// `None` unless both are given, so an incomplete pair silently disables the
+/// row instead of guessing a coordinate.
+fn weather_config_from_flags() -> Option<weather::WeatherConfig> {
+    let latitude = flag_value("--weather-lat")?.parse().ok()?;
+    let longitude = flag_value("--weather-lon")?.parse().ok()?;
+    Some(weather::WeatherConfig { latitude: Some(latitude), longitude: Some(longitude) })
+}
+
+/// Builds a single-entry [`status::StatusEntry`] list from `--status-message`
+/// (plus optional `--status-from`/`--status-until`). Empty unless
+/// `--status-message` was given, so the "Status" row is omitted by default.
+fn status_entries_from_flags() -> Vec<status::StatusEntry> {
+    let Some(message) = flag_value("--status-message") else {
+        return Vec::new();
+    };
+    vec![status::StatusEntry { message, from: flag_value("--status-from"), until: flag_value("--status-until") }]
+}
+
+/// Uploads `content` to a gist if `--gist` was passed, updating
+/// `--gist-id` in place when given so the hotlink URL stays stable across
+/// runs instead of a new gist appearing every time.
+fn maybe_upload_gist(client: &GithubClient, filename: &str, content: &str) -> Result<()> {
+    if !flag_present("--gist") {
+        return Ok(());
+    }
+    let gist_id = flag_value("--gist-id");
+    let url = client.upload_gist(gist_id.as_deref(), filename, content)?;
+    println!("uploaded gist: {url}");
+    Ok(())
+}
+
+/// Pushes `content` to `--commit-to owner/repo` (defaults to `--commit-branch`,
+/// `assets`) at `filename`, if `--commit-to` was passed.
+fn maybe_commit_to_repo(client: &GithubClient, filename: &str, content: &str) -> Result<()> {
+    let Some(target) = flag_value("--commit-to") else {
+        return Ok(());
+    };
+    let (owner, repo) = target.split_once('/').expect("--commit-to must be OWNER/REPO");
+    let branch = flag_value("--commit-branch").unwrap_or_else(|| "assets".to_string());
+    client.put_file(owner, repo, &branch, filename, content, &format!("update {filename}"))?;
+    println!("committed {filename} to {target}@{branch}");
+    Ok(())
+}
+
+/// Pushes `stats` to `--metrics-push-url` in `--metrics-push-format`
+/// (default `influx`), if `--metrics-push-url` was passed.
+fn maybe_push_metrics(stats: &Stats) -> Result<()> {
+    let Some(url) = flag_value("--metrics-push-url") else {
+        return Ok(());
+    };
+    let format = metrics_push::format_from_flag(flag_value("--metrics-push-format").as_deref());
+    metrics_push::push(stats, &url, format)?;
+    println!("pushed metrics to {url}");
+    Ok(())
+}
+
+/// Posts a run summary to `--webhook-url` (Discord or Slack incoming
+/// webhook, detected from the URL), if passed. `--webhook-include-image`
+/// additionally attaches `image` (Discord only — see [`webhook`]).
+fn maybe_notify_webhook(stats: &Stats, image: Option<&[u8]>) -> Result<()> {
+    let Some(url) = flag_value("--webhook-url") else {
+        return Ok(());
+    };
+    webhook::notify(&url, stats, image)?;
+    println!("notified webhook");
+    Ok(())
+}
+
+/// Posts a run summary to Telegram via `--notify-telegram`, if passed —
+/// requires `TELEGRAM_BOT_TOKEN`/`TELEGRAM_CHAT_ID` to be resolvable through
+/// [`secrets`], same as `ACCESS_TOKEN`. `--webhook-include-image`
+/// additionally attaches `image`, same as the Discord webhook.
+fn maybe_notify_telegram(stats: &Stats, image: Option<&[u8]>) -> Result<()> {
+    if !flag_present("--notify-telegram") {
+        return Ok(());
+    }
+    let bot_token = secrets::resolve("TELEGRAM_BOT_TOKEN").expect("TELEGRAM_BOT_TOKEN must be set via env var, .env file, or OS keychain");
+    let chat_id = secrets::resolve("TELEGRAM_CHAT_ID").expect("TELEGRAM_CHAT_ID must be set via env var, .env file, or OS keychain");
+    telegram::TelegramNotifier { bot_token, chat_id }.notify(stats, image)?;
+    println!("notified Telegram");
+    Ok(())
+}
+
+/// One card to render in this run. Batches come from repeating `--username`
+/// on the command line; each card fetches its own stats but shares the
+/// `GithubClient`'s on-disk LOC cache, so overlapping repos across users
+/// aren't re-walked.
+struct CardSpec {
+    username: String,
+    /// `true` in third-person mode, where [`BIRTHDAY`] doesn't apply.
+    third_person: bool,
+    output_path: String,
+}
+
+/// `--output-dir <dir>` in place of the hardcoded `"dist"` prefix on
+/// [`OUTPUT_PATH`] and the batch per-user paths [`card_specs`] builds.
+fn output_dir() -> String {
+    flag_value("--output-dir").unwrap_or_else(|| "dist".to_string())
+}
+
+/// `--theme dark`/`dracula`/`gruvbox`/`catppuccin`/`solarized` selects that
+/// built-in theme; anything else, including no flag, keeps [`Theme::Default`].
+/// Mirrors `Config::theme`'s mapping for `server` mode's config file.
+fn theme_from_flag() -> Theme {
+    match flag_value("--theme").as_deref() {
+        Some("dark") => Theme::Dark,
+        Some("dracula") => Theme::Dracula,
+        Some("gruvbox") => Theme::Gruvbox,
+        Some("catppuccin") => Theme::Catppuccin,
+        Some("solarized") => Theme::Solarized,
+        _ => Theme::Default,
+    }
+}
+
+/// Loads [`ThemeColors`] from the JSON document at `--theme-file`, if given
+/// — overrides whatever `--theme` picked. Mirrors `Config::custom_theme`'s
+/// mapping for `server` mode's config file.
+fn custom_theme_from_flag() -> Result<Option<ThemeColors>> {
+    let Some(path) = flag_value("--theme-file") else {
+        return Ok(None);
+    };
+    let raw = std::fs::read_to_string(path)?;
+    let config: CustomThemeConfig = serde_json::from_str(&raw)?;
+    Ok(Some(config.to_theme_colors()))
+}
+
+/// Loads the left-column ASCII art from `--ascii-art-file`, if given, falling
+/// back to [`ascii::DEFAULT_ASCII_ART`] (with a warning) on a missing or
+/// unreadable path. Mirrors `Config::ascii_art_file`'s handling for `server`
+/// mode's config file.
+fn ascii_art_from_flag() -> String {
+    match flag_value("--ascii-art-file") {
+        Some(path) => std::fs::read_to_string(&path).unwrap_or_else(|e| {
+            eprintln!("warning: --ascii-art-file {path} unreadable ({e}), using the default art");
+            ascii::DEFAULT_ASCII_ART.to_string()
+        }),
+        None => ascii::DEFAULT_ASCII_ART.to_string(),
+    }
+}
+
+/// Fetches and base64-inlines `username`'s GitHub avatar for `--show-avatar`,
+/// falling back to `None` (leaving the caller to show ASCII art instead) with
+/// a warning if either the lookup or the image download fails — like
+/// `--weather-lat`/`-lon`, a third-party asset going missing shouldn't fail
+/// the whole render.
+fn avatar_from_flag(client: &GithubClient, username: &str) -> Option<String> {
+    if !flag_present("--show-avatar") {
+        return None;
+    }
+    match client.avatar_url(username).and_then(|url| avatar::fetch_base64(&url)) {
+        Ok(data_uri) => Some(data_uri),
+        Err(e) => {
+            eprintln!("warning: --show-avatar failed ({e}), using ASCII art instead");
+            None
+        }
+    }
+}
+
+/// Reads every `--username <login>` off argv. Defaults to a single
+/// first-person card for [`USERNAME`] so `halfguru generate` with no flags
+/// keeps producing the author's own card written under [`output_dir`]; each
+/// explicit `--username` switches that card to third-person mode and writes
+/// to `<output-dir>/<login>.svg` instead, so a batch run doesn't clobber
+/// itself.
+fn card_specs() -> Vec<CardSpec> {
+    let dir = output_dir();
+    let usernames = usernames_from_args();
+    if usernames.is_empty() {
+        return vec![CardSpec { username: USERNAME.to_string(), third_person: false, output_path: format!("{dir}/card.svg") }];
+    }
+    usernames
+        .into_iter()
+        .map(|username| {
+            let output_path = format!("{dir}/{username}.svg");
+            CardSpec { username, third_person: true, output_path }
+        })
+        .collect()
+}
+
+fn render_card(client: &GithubClient, spec: &CardSpec) -> Result<()> {
+    render_card_themed(client, spec, theme_from_flag())
+}
+
+/// Renders every [`card_specs`] entry, continuing past a single card's
+/// failure instead of aborting the whole batch — so `--username a --username
+/// b` with a typo in `b` still produces `a`'s card. Exits directly with
+/// [`EXIT_PARTIAL_SUCCESS`] if only some cards failed, since that outcome
+/// isn't a single [`Error`] `main`'s normal `Result` handling can carry.
+fn run_cards(client: &GithubClient) -> Result<()> {
+    let specs = card_specs();
+    let mut last_error = None;
+    let mut failures = 0;
+    for spec in &specs {
+        if let Err(e) = render_card(client, spec) {
+            eprintln!("error rendering {}: {e}", spec.username);
+            failures += 1;
+            last_error = Some(e);
+        }
+    }
+    match last_error {
+        None => Ok(()),
+        Some(e) if failures == specs.len() => Err(e),
+        Some(_) => {
+            telemetry::shutdown();
+            std::process::exit(EXIT_PARTIAL_SUCCESS);
+        }
+    }
+}
+
+fn render_card_themed(client: &GithubClient, spec: &CardSpec, theme: Theme) -> Result<()> {
+    let custom_theme = custom_theme_from_flag()?;
+    for warning in contrast::validate_theme(custom_theme.as_ref().unwrap_or(&theme.colors())) {
+        eprintln!("warning: {warning}");
+    }
+    let ascii_art = ascii_art_from_flag();
+    for warning in ascii::validate(&ascii_art) {
+        eprintln!("warning: {warning}");
+    }
+    if flag_present("--debug-dump") {
+        return write_debug_dump(client, spec);
+    }
+    let birthday_value = birthday();
+    let birthday = (!spec.third_person).then_some(birthday_value.as_str());
+    let after_hours = flag_present("--after-hours").then(|| render::AfterHoursOptions { utc_offset_hours: 0, config: Default::default() });
+    let streak = flag_present("--show-streak").then(|| render::StreakOptions { utc_offset_hours: 0, config: Default::default() });
+    let quote_config = quote_config_from_flags();
+    let weather_config = weather_config_from_flags();
+    let status_entries = status_entries_from_flags();
+    let mut model = render::build_model(
+        client,
+        &spec.username,
+        birthday,
+        flag_present("--show-collaborators"),
+        flag_present("--show-star-history"),
+        flag_present("--show-spotlight"),
+        flag_present("--show-contribution-history"),
+        flag_present("--show-top-languages"),
+        flag_present("--show-commits-all-time"),
+        after_hours.as_ref(),
+        streak.as_ref(),
+        &[],
+        quote_config.as_ref(),
+        weather_config.as_ref(),
+        &status_entries,
+        &[],
+        &[],
+        &[],
+        None,
+        flag_present("--skip-loc"),
+        None,
+        flag_present("--enable-plugins"),
+    )?;
+    model.options.custom_theme = custom_theme;
+    model.options.ascii_art = ascii_art;
+    model.options.avatar = avatar_from_flag(client, &spec.username);
+    for warning in svg::validate_row_widths(&model.stats, model.age.as_deref(), &model.options) {
+        eprintln!("warning: {warning}");
+    }
+
+    if flag_present("--dump-model") {
+        let json = serde_json::to_string_pretty(&model.to_sections())?;
+        println!("{json}");
+        return Ok(());
+    }
+
+    if flag_present("--dual-theme") {
+        return write_dual_theme(&model, spec);
+    }
+
+    let svg = SvgRenderer.render(&model, &theme).remove(0).content;
+
+    if flag_present("--check") {
+        let existing = std::fs::read_to_string(&spec.output_path).unwrap_or_default();
+        if normalize_for_diff(&existing) == normalize_for_diff(&svg) {
+            println!("{} is up to date", spec.output_path);
+            return Ok(());
+        }
+        println!("{} would change", spec.output_path);
+        std::process::exit(CHECK_EXIT_STALE);
+    }
+
+    if let Some(parent) = std::path::Path::new(&spec.output_path).parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let formats = export::formats_from_flag(flag_value("--format").as_deref());
+    if formats.contains(&export::Format::Svg) {
+        std::fs::write(&spec.output_path, &svg)?;
+        println!("wrote {}", spec.output_path);
+    }
+    if formats.contains(&export::Format::Png) {
+        let png_path = export::path_for_format(&spec.output_path, export::Format::Png);
+        match export::to_png(&svg) {
+            Ok(bytes) => {
+                std::fs::write(&png_path, &bytes)?;
+                println!("wrote {png_path}");
+            }
+            Err(e) => eprintln!("warning: PNG export skipped: {e}"),
+        }
+    }
+    if formats.contains(&export::Format::Csv) {
+        let csv_path = export::path_for_format(&spec.output_path, export::Format::Csv);
+        csv_export::append(std::path::Path::new(&csv_path), &model.stats)?;
+        println!("wrote {csv_path}");
+    }
+    if formats.contains(&export::Format::Html) {
+        let html_path = export::path_for_format(&spec.output_path, export::Format::Html);
+        let rendered = SvgRenderer.render_multi(&model, &[Theme::Default, Theme::Dark]);
+        let [light_svg, dark_svg]: [String; 2] =
+            rendered.try_into().unwrap_or_else(|_| panic!("render_multi returns one SVG per requested theme"));
+        std::fs::write(&html_path, html_widget::build_from(&light_svg, &dark_svg))?;
+        println!("wrote {html_path}");
+    }
+
+    let filename = std::path::Path::new(&spec.output_path).file_name().and_then(|f| f.to_str()).unwrap_or("card.svg");
+    maybe_upload_gist(client, filename, &svg)?;
+    maybe_commit_to_repo(client, filename, &svg)?;
+    maybe_push_metrics(&model.stats)?;
+    let webhook_image = if flag_present("--webhook-include-image") {
+        match export::to_png(&svg) {
+            Ok(bytes) => Some(bytes),
+            Err(e) => {
+                eprintln!("warning: webhook image attachment skipped: {e}");
+                None
+            }
+        }
+    } else {
+        None
+    };
+    maybe_notify_webhook(&model.stats, webhook_image.as_deref())?;
+    maybe_notify_telegram(&model.stats, webhook_image.as_deref())?;
+    Ok(())
+}
+
+/// Returns `path` with `suffix` inserted before the extension,
+/// e.g. `with_theme_suffix("dist/card.svg", "dark")` -> `"dist/card-dark.svg"`.
+fn with_theme_suffix(path: &str, suffix: &str) -> String {
+    match path.rsplit_once('.') {
+        Some((stem, ext)) => format!("{stem}-{suffix}.{ext}"),
+        None => format!("{path}-{suffix}"),
+    }
+}
+
+/// `--dual-theme`: writes a light and dark SVG side by side instead of one
+/// theme-neutral file, plus a ready-to-paste `<picture>`/Markdown snippet
+/// (see [`picture::snippet`]) wiring the pair into a README.
+fn write_dual_theme(model: &render::RenderModel, spec: &CardSpec) -> Result<()> {
+    let light_path = with_theme_suffix(&spec.output_path, "light");
+    let dark_path = with_theme_suffix(&spec.output_path, "dark");
+    let rendered = SvgRenderer.render_multi(model, &[Theme::Default, Theme::Dark]);
+    let [light_svg, dark_svg]: [String; 2] =
+        rendered.try_into().unwrap_or_else(|_| panic!("render_multi returns one SVG per requested theme"));
+
+    if let Some(parent) = std::path::Path::new(&light_path).parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&light_path, &light_svg)?;
+    std::fs::write(&dark_path, &dark_svg)?;
+    println!("wrote {light_path}");
+    println!("wrote {dark_path}");
+
+    let snippet = picture::snippet(&light_path, &dark_path, &format!("{}'s GitHub stats", spec.username));
+    match flag_value("--picture-out") {
+        Some(picture_path) => {
+            std::fs::write(&picture_path, &snippet)?;
+            println!("wrote {picture_path}");
+        }
+        None => println!("{snippet}"),
+    }
+    Ok(())
+}
+
+/// `--debug-dump`: re-renders `spec` with a scratch dev-cache directory so
+/// this run's raw API responses are on disk to collect, then zips them with
+/// the computed stats/model/environment info to `--debug-dump-out` (default
+/// `halfguru-debug.zip`) for attaching to a bug report.
+fn write_debug_dump(client: &GithubClient, spec: &CardSpec) -> Result<()> {
+    let dev_cache_dir = std::env::temp_dir().join(format!("halfguru-debug-dump-{}", spec.username));
+    let dump_client = client.clone().with_dev_cache(true).with_cache_dir(dev_cache_dir.clone());
+
+    let birthday_value = birthday();
+    let birthday = (!spec.third_person).then_some(birthday_value.as_str());
+    let model = render::build_model(
+        &dump_client,
+        &spec.username,
+        birthday,
+        true,
+        true,
+        true,
+        true,
+        true,
+        true,
+        None,
+        None,
+        &[],
+        None,
+        None,
+        &[],
+        &[],
+        &[],
+        &[],
+        None,
+        false,
+        None,
+        true,
+    )?;
+
+    let output_path = flag_value("--debug-dump-out").unwrap_or_else(|| "halfguru-debug.zip".to_string());
+    debug_dump::write(&dump_client, &model, &dev_cache_dir, std::path::Path::new(&output_path))?;
+    println!("wrote {output_path}");
+    let _ = std::fs::remove_dir_all(&dev_cache_dir);
+    Ok(())
+}
+
+fn run_leaderboard(client: &GithubClient) -> Result<()> {
+    let usernames = usernames_from_args();
+    let entries = leaderboard::fetch_entries(client, &usernames)?;
+    let svg = leaderboard::render_leaderboard(&entries, Theme::Default);
+
+    if let Some(parent) = std::path::Path::new(LEADERBOARD_OUTPUT_PATH).parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(LEADERBOARD_OUTPUT_PATH, svg)?;
+    println!("wrote {LEADERBOARD_OUTPUT_PATH}");
+    Ok(())
+}
+
+/// Runs `halfguru verify`: recomputes stats that have an independent second
+/// path through the API for `--username` (default: [`USERNAME`]) and exits
+/// non-zero if any of them disagree.
+fn run_verify(client: &GithubClient) -> Result<()> {
+    let username = flag_value("--username").unwrap_or_else(|| USERNAME.to_string());
+    if !verify::run(client, &username)? {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn run_repo_card(client: &GithubClient) -> Result<()> {
+    let owner = flag_value("--owner").unwrap_or_else(|| USERNAME.to_string());
+    let repo = flag_value("--repo").expect("--repo <name> is required for repo-card");
+
+    let info = client.repo_info(&owner, &repo)?;
+    let svg = repo_card::render_repo_card(&info, Theme::Default);
+
+    let output_path = format!("{REPO_CARD_OUTPUT_DIR}/{repo}-card.svg");
+    if let Some(parent) = std::path::Path::new(&output_path).parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&output_path, svg)?;
+    println!("wrote {output_path}");
+    Ok(())
+}
+
+/// Prints a completion script for the `<shell>` argument (see `completions
+/// -h` for the supported names) to stdout, for e.g. `halfguru completions bash >
+/// /etc/bash_completion.d/halfguru`.
+fn run_completions() -> Result<()> {
+    let shell_name = std::env::args().nth(2).expect("halfguru completions <shell>");
+    let shell: clap_complete::Shell = shell_name.parse().unwrap_or_else(|_| panic!("unsupported shell: {shell_name}"));
+    let mut cmd = cli::command();
+    let name = cmd.get_name().to_string();
+    clap_complete::generate(shell, &mut cmd, name, &mut std::io::stdout());
+    Ok(())
+}
+
+/// Prints a man page derived from [`cli::command`] to stdout, for e.g.
+/// `halfguru man > /usr/local/share/man/man1/halfguru.1`.
+fn run_man() -> Result<()> {
+    let cmd = cli::command();
+    clap_mangen::Man::new(cmd).render(&mut std::io::stdout())?;
+    Ok(())
+}
+
+/// Prints [`schema::generate`]'s JSON Schema for the config file to stdout,
+/// for `halfguru schema > halfguru.schema.json` and editor
+/// `"$schema"`/`json.schemas` wiring.
+fn run_schema() -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(&schema::generate())?);
+    Ok(())
+}
+
+/// Runs `halfguru inject`: splices an already-rendered SVG (see
+/// [`inject::inject`]) into `--readme` (default `README.md`) between
+/// `halfguru:start`/`halfguru:end` markers. Reads `--svg` (default
+/// `<output-dir>/card.svg`) off disk rather than rendering it itself, since a
+/// normal `halfguru generate` run has usually already produced it — this just
+/// wires the result into the README the way `--dual-theme` leaves a user to
+/// do by hand with [`picture::snippet`].
+fn run_inject() -> Result<()> {
+    let readme_path = flag_value("--readme").unwrap_or_else(|| "README.md".to_string());
+    let svg_path = flag_value("--svg").unwrap_or_else(|| format!("{}/card.svg", output_dir()));
+    let alt = flag_value("--alt").unwrap_or_else(|| "GitHub stats card".to_string());
+    let svg_content = std::fs::read_to_string(&svg_path)?;
+    let embed = inject::embed(&svg_path, &svg_content, &alt, flag_present("--inline"));
+    let readme = std::fs::read_to_string(&readme_path)?;
+    std::fs::write(&readme_path, inject::inject(&readme, &embed)?)?;
+    println!("updated {readme_path}");
+    Ok(())
+}
+
+/// Runs the `halfguru doctor` environment checks. Unlike every other
+/// subcommand, a missing `ACCESS_TOKEN` is itself one of the things being
+/// diagnosed, so this builds the client from whatever's set (possibly
+/// nothing) instead of `expect`-ing it up front.
+fn run_doctor() -> Result<()> {
+    let token = secrets::resolve("ACCESS_TOKEN").unwrap_or_default();
+    let mut client = GithubClient::new(token);
+    if let Some(cache_dir) = flag_value("--cache-dir") {
+        client = client.with_cache_dir(std::path::PathBuf::from(cache_dir));
+    }
+    let config_path = flag_value("--config").map(std::path::PathBuf::from).unwrap_or_else(default_config_path);
+
+    if !doctor::run(&client, &config_path) {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Runs indefinitely, re-rendering the default profile card every
+/// [`SERVER_TICK`] with the theme the watched config file holds at that
+/// tick, so a theme change applies on the next tick without restarting the
+/// process. `excluded_repos` isn't applied to the render.
+fn run_server(client: &GithubClient) -> Result<()> {
+    let config_path = flag_value("--config").map(std::path::PathBuf::from).unwrap_or_else(default_config_path);
+    let initial = config::load(&config_path).unwrap_or_default();
+    let shared = Arc::new(RwLock::new(initial));
+
+    let _watcher = server::watch_config(config_path, shared.clone());
+    if let Err(e) = &_watcher {
+        eprintln!("config hot-reload disabled, watcher failed to start: {e}");
+    }
+
+    let health_addr = flag_value("--health-addr").unwrap_or_else(|| DEFAULT_HEALTH_ADDR.to_string());
+    let ready = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+    let health_options = server::HealthServerOptions {
+        auth_token: flag_value("--health-token"),
+        rate_limit_per_minute: flag_value("--health-rate-limit")
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_else(|| server::HealthServerOptions::default().rate_limit_per_minute),
+    };
+    if let Err(e) = server::serve_health(&health_addr, client.clone(), ready.clone(), health_options, USERNAME.to_string(), shared.clone()) {
+        eprintln!("health/readiness endpoints disabled, failed to bind {health_addr}: {e}");
+    }
+
+    loop {
+        let theme = shared.read().expect("config lock poisoned").theme();
+        let spec = CardSpec { username: USERNAME.to_string(), third_person: false, output_path: OUTPUT_PATH.to_string() };
+        match render_card_themed(client, &spec, theme) {
+            Ok(()) => ready.store(true, std::sync::atomic::Ordering::Relaxed),
+            Err(e) => eprintln!("card render failed: {e}"),
+        }
+        std::thread::sleep(SERVER_TICK);
+    }
+}
+
+fn main() -> Result<()> {
+    // Doesn't need ACCESS_TOKEN — they either talk to no API at all or only
+    // the public releases API.
+    match std::env::args().nth(1).as_deref() {
+        Some("self-update") => return self_update::run(VERSION),
+        Some("completions") => return run_completions(),
+        Some("man") => return run_man(),
+        Some("doctor") => return run_doctor(),
+        Some("schema") => return run_schema(),
+        Some("inject") => return run_inject(),
+        _ => {}
+    }
+
+    let otlp_endpoint = flag_value("--otlp-endpoint").or_else(|| std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT").ok());
+    telemetry::init(otlp_endpoint.as_deref());
+
+    let token = secrets::resolve("ACCESS_TOKEN").expect("ACCESS_TOKEN must be set via env var, .env file, or OS keychain");
+    let mut client = GithubClient::new(token).with_dev_cache(flag_present("--dev-cache"));
+    if let Some(cache_dir) = flag_value("--cache-dir") {
+        client = client.with_cache_dir(std::path::PathBuf::from(cache_dir));
+    }
+
+    let result = match std::env::args().nth(1).as_deref() {
+        Some("leaderboard") => run_leaderboard(&client),
+        Some("repo-card") => run_repo_card(&client),
+        Some("server") => run_server(&client),
+        Some("verify") => run_verify(&client),
+        _ => run_cards(&client),
+    };
+
+    // `server` mode runs forever, so this only fires for one-shot CLI runs.
+    telemetry::shutdown();
+    if let Err(e) = &result {
+        fail(e);
+    }
+    result
+}
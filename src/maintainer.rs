@@ -0,0 +1,32 @@
+//! Config-driven "Maintainer dashboard" section: one row per
+//! `Config::maintained_repos` entry showing its open-issue counts by label,
+//! fetched via [`crate::github::GithubClient::maintainer_dashboard`].
+//! Rendered the same way as [`crate::skills`] — a stacked-row layout via
+//! `svg.rs`'s shared component system — but, like [`crate::spotlight`], its
+//! rows come from a live API call rather than static configuration alone.
+
+use crate::github::MaintainedRepoLabels;
+
+const ROW_HEIGHT: u32 = 18;
+
+/// Vertical space `entries` will occupy when rendered, `0` if empty.
+pub fn height(entries: &[MaintainedRepoLabels]) -> u32 {
+    if entries.is_empty() { 0 } else { entries.len() as u32 * ROW_HEIGHT }
+}
+
+/// Renders one `"repo — N bugs, N enhancements, N help wanted"` row per
+/// entry, stacked downward from `(x, y)`.
+pub fn render_dashboard(entries: &[MaintainedRepoLabels], x: u32, y: u32, text_attr: &str) -> String {
+    entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let row_y = y + i as u32 * ROW_HEIGHT;
+            format!(
+                r#"<text x="{x}" y="{row_y}" {text_attr}>{} — {} bugs, {} enhancements, {} help wanted</text>"#,
+                entry.repo, entry.bugs, entry.enhancements, entry.help_wanted,
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n    ")
+}
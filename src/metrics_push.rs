@@ -0,0 +1,87 @@
+//! Optional push of a run's [`Stats`] to a time-series backend, for CI usage
+//! where nothing is around afterward to scrape a pull-based endpoint: a
+//! one-shot `halfguru` invocation in a workflow can push its numbers to
+//! InfluxDB (line protocol) or a Prometheus Pushgateway before the job
+//! exits, instead of losing them the moment the runner is torn down.
+//!
+//! Disabled unless `--metrics-push-url` is passed. A push failure is
+//! propagated like the `--gist`/`--commit-to` uploads in `main.rs`, rather
+//! than swallowed like [`crate::weather::fetch`] — a CI job that asked for
+//! this wants to know its numbers didn't land.
+
+use crate::error::Result;
+use crate::stats::Stats;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PushFormat {
+    InfluxLineProtocol,
+    PrometheusPushgateway,
+}
+
+/// `"prometheus"` selects [`PushFormat::PrometheusPushgateway`]; anything
+/// else (including the flag being omitted) defaults to
+/// [`PushFormat::InfluxLineProtocol`], the more common target for this flag.
+pub fn format_from_flag(value: Option<&str>) -> PushFormat {
+    match value {
+        Some("prometheus") => PushFormat::PrometheusPushgateway,
+        _ => PushFormat::InfluxLineProtocol,
+    }
+}
+
+/// POSTs `stats` to `url` in `format`, failing on any non-2xx response or
+/// transport error.
+pub fn push(stats: &Stats, url: &str, format: PushFormat) -> Result<()> {
+    let (body, content_type) = match format {
+        PushFormat::InfluxLineProtocol => (to_influx_line(stats), "text/plain"),
+        PushFormat::PrometheusPushgateway => (to_prometheus_exposition(stats), "text/plain; version=0.0.4"),
+    };
+    reqwest::blocking::Client::new().post(url).header("Content-Type", content_type).body(body).send()?.error_for_status()?;
+    Ok(())
+}
+
+/// A single InfluxDB line-protocol point: `measurement,tag=... field=val,...`,
+/// with no explicit timestamp — the server stamps it on receipt.
+fn to_influx_line(stats: &Stats) -> String {
+    format!("halfguru_stats,username={} {}", escape_tag_value(&stats.username), fields(stats).into_iter().map(|(k, v)| format!("{k}={v}")).collect::<Vec<_>>().join(","))
+}
+
+/// Prometheus text exposition format, one gauge per field, each carrying
+/// `username` as a label so a single pushgateway can hold more than one
+/// user's card.
+fn to_prometheus_exposition(stats: &Stats) -> String {
+    fields(stats)
+        .into_iter()
+        .map(|(name, value)| format!("halfguru_{name}{{username=\"{}\"}} {value}\n", escape_label_value(&stats.username)))
+        .collect()
+}
+
+/// Numeric fields common to both formats, in the order they should appear.
+fn fields(stats: &Stats) -> Vec<(&'static str, String)> {
+    let mut fields = vec![
+        ("stars", stats.stars.to_string()),
+        ("commits", stats.commits.to_string()),
+        ("repos", stats.repos.to_string()),
+        ("followers", stats.followers.to_string()),
+        ("loc_add", stats.loc_add.to_string()),
+        ("loc_del", stats.loc_del.to_string()),
+    ];
+    if let Some(v) = stats.current_streak {
+        fields.push(("current_streak", v.to_string()));
+    }
+    if let Some(v) = stats.longest_streak {
+        fields.push(("longest_streak", v.to_string()));
+    }
+    fields
+}
+
+/// Escapes a value used as an unquoted InfluxDB tag value: commas, spaces,
+/// and equals signs are syntactically significant there.
+fn escape_tag_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace(',', "\\,").replace(' ', "\\ ").replace('=', "\\=")
+}
+
+/// Escapes a value used inside a quoted Prometheus label: backslashes,
+/// quotes, and newlines need escaping there instead.
+fn escape_label_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
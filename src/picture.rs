@@ -0,0 +1,24 @@
+//! Builds the ready-to-paste README snippet for a light/dark asset pair (see
+//! `--dual-theme` in `main.rs`), so users don't have to hand-write the
+//! `<picture>` markup or GitHub's `#gh-dark-mode-only` anchor convention
+//! themselves.
+
+/// A `<picture>` element using `prefers-color-scheme` (works in any Markdown
+/// renderer that passes raw HTML through), followed by GitHub's own
+/// `#gh-dark-mode-only`/`#gh-light-mode-only` anchor convention as a fallback
+/// comment, since READMEs rendered on github.com strip `<picture>` sources
+/// down to just the `<img>`.
+pub fn snippet(light_path: &str, dark_path: &str, alt: &str) -> String {
+    format!(
+        r#"<picture>
+  <source media="(prefers-color-scheme: dark)" srcset="{dark_path}">
+  <source media="(prefers-color-scheme: light)" srcset="{light_path}">
+  <img alt="{alt}" src="{light_path}">
+</picture>
+
+<!-- github.com strips <picture> sources from rendered READMEs; if that's
+     your target, use its own dark/light anchor convention instead:
+![{alt}]({dark_path}#gh-dark-mode-only)
+![{alt}]({light_path}#gh-light-mode-only) -->"#
+    )
+}
@@ -0,0 +1,104 @@
+//! Third-party collector plugins, discovered the same way `cargo` finds
+//! `cargo-<name>` subcommands: any `halfguru-collector-<name>` executable on
+//! `PATH` is picked up automatically, so someone can ship a collector for a
+//! niche platform this crate has no built-in support for without patching
+//! it or waiting on a release.
+//!
+//! Each discovered executable is run with the same timeout-and-no-shell
+//! treatment as `Config::custom_command` (see
+//! [`crate::custom_section::run_with_timeout`]), and is expected to print a
+//! single flat JSON object of `"key": "value"` rows to stdout — a simple
+//! enough protocol that a plugin can be a one-line shell script piping into
+//! `jq`, not just a compiled binary.
+//!
+//! This is a broader trust boundary than `Config::custom_command`: instead
+//! of one command an operator explicitly configured, *anything* on `PATH`
+//! matching the naming convention gets run. It's opt-in via
+//! [`Config::enable_plugins`](crate::config::Config::enable_plugins) for
+//! that reason, and even then it's only as safe as the machine's `PATH` —
+//! don't turn it on anywhere an untrusted user can drop a file into a
+//! directory ahead of the trusted one.
+
+use serde_json::Value;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Prefix a `PATH` executable must have to be discovered as a collector,
+/// e.g. `halfguru-collector-wakatime`.
+pub const COLLECTOR_PREFIX: &str = "halfguru-collector-";
+
+/// Seconds to wait for a single collector before killing it and moving on.
+const TIMEOUT_SECS: u64 = 5;
+
+/// Runs every collector [`discover`] finds and merges their rows in
+/// discovery order. A collector that fails, times out, or doesn't print a
+/// parseable JSON object contributes no rows rather than aborting the
+/// others — like [`crate::custom_section::run`], a plugin is best-effort,
+/// not load-bearing.
+pub fn collect_all() -> Vec<(String, String)> {
+    discover().iter().flat_map(|path| run_collector(path)).collect()
+}
+
+/// Every `PATH` entry matching [`COLLECTOR_PREFIX`], in the order `PATH`
+/// lists directories and de-duplicated by collector name so an earlier
+/// directory's copy shadows a same-named one further down, like shell
+/// command lookup.
+fn discover() -> Vec<PathBuf> {
+    let Some(path_var) = std::env::var_os("PATH") else {
+        return Vec::new();
+    };
+    let mut seen = HashSet::new();
+    std::env::split_paths(&path_var)
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter_map(|path| collector_name(&path).map(|name| (name, path)))
+        .filter(|(_, path)| is_executable(path))
+        .filter(|(name, _)| seen.insert(name.clone()))
+        .map(|(_, path)| path)
+        .collect()
+}
+
+/// `halfguru-collector-foo` (and, on Windows, `halfguru-collector-foo.exe`)
+/// -> `Some("foo")`; anything else -> `None`.
+fn collector_name(path: &Path) -> Option<String> {
+    path.file_stem()?.to_str()?.strip_prefix(COLLECTOR_PREFIX).map(str::to_string)
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path).is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+}
+
+/// Windows has no POSIX execute bit, so a matching filename is treated as
+/// executable on its own — `Command::spawn` is the real check, and it fails
+/// harmlessly for anything that isn't.
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    std::fs::metadata(path).is_ok_and(|meta| meta.is_file())
+}
+
+fn run_collector(path: &Path) -> Vec<(String, String)> {
+    crate::custom_section::run_with_timeout(path, &[], Duration::from_secs(TIMEOUT_SECS)).map(|output| parse_json_rows(&output)).unwrap_or_default()
+}
+
+/// Parses stdout as a single flat JSON object, coercing non-string values to
+/// their JSON text so a collector can emit numbers or booleans without the
+/// caller having to guess a display format for them.
+fn parse_json_rows(output: &[u8]) -> Vec<(String, String)> {
+    let Ok(Value::Object(map)) = serde_json::from_slice::<Value>(output) else {
+        return Vec::new();
+    };
+    map.into_iter()
+        .map(|(key, value)| {
+            let value = match value {
+                Value::String(s) => s,
+                other => other.to_string(),
+            };
+            (key, value)
+        })
+        .collect()
+}
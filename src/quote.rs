@@ -0,0 +1,46 @@
+//! Picks a quote for the optional "quote" row on a profile card, either
+//! genuinely at random or deterministically per calendar day — the latter so
+//! a daily CI-scheduled re-render doesn't produce a diff-only-in-the-quote
+//! commit every single day.
+
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::hash::{Hash, Hasher};
+
+/// A list of quotes to pick from, as configured under `Config::quote`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct QuoteConfig {
+    /// Quotes to pick from. An empty list means no quote row is shown.
+    #[serde(default)]
+    pub quotes: Vec<String>,
+    /// When `true`, the same quote is shown all day (picked from
+    /// [`today`]'s date) instead of a fresh one on every render, so a
+    /// scheduled CI run doesn't rewrite the card's diff every tick.
+    #[serde(default)]
+    pub daily_seed: bool,
+}
+
+/// Picks a quote from `config.quotes`, or `None` if the list is empty.
+/// `today` is threaded in by the caller (rather than read from the clock
+/// here) so a deterministic pick can be tested against a fixed date.
+pub fn pick(config: &QuoteConfig, today: NaiveDate) -> Option<&str> {
+    if config.quotes.is_empty() {
+        return None;
+    }
+    let index = if config.daily_seed { seed_for(today) } else { pseudo_random_index() } % config.quotes.len();
+    Some(&config.quotes[index])
+}
+
+fn seed_for(today: NaiveDate) -> usize {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    today.hash(&mut hasher);
+    hasher.finish() as usize
+}
+
+/// A dependency-free stand-in for a random index, matching the same
+/// low-bits-of-the-clock approach [`crate::github`]'s retry jitter uses —
+/// this isn't security-sensitive, it only needs to vary run to run.
+fn pseudo_random_index() -> usize {
+    let nanos = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map(|d| d.subsec_nanos()).unwrap_or(0);
+    nanos as usize
+}
@@ -0,0 +1,523 @@
+//! Pluggable output: a [`Renderer`] turns a [`RenderModel`] into [`Artifact`]s,
+//! so SVG, PNG, HTML, terminal, and Markdown renderers all consume the same
+//! structured input instead of each re-deriving rows from [`Stats`].
+
+use crate::afterhours::AfterHoursConfig;
+use crate::custom_section::CustomCommandConfig;
+use crate::error::{Error, Result};
+use crate::github::{ContributionMix, GithubClient, MaintainedRepoLabels, SpotlightRepo, StarHistoryPoint, YearlyContributions};
+use crate::postprocess::StatProcessor;
+use crate::quote::QuoteConfig;
+use crate::skills::SkillEntry;
+use crate::stats::Stats;
+use crate::status::StatusEntry;
+use crate::streak::StreakConfig;
+use crate::weather::WeatherConfig;
+use crate::svg::{self, LayoutOptions, Theme};
+use crate::timeline::TimelineEntry;
+use crate::trophies::Trophy;
+use serde::{Deserialize, Serialize};
+
+/// Knobs for the "After-hours coder" stat, bundling the shared UTC offset
+/// with its own work-window config. `None` in [`build_model`] skips
+/// computing it entirely, since it costs an extra history walk per repo.
+pub struct AfterHoursOptions {
+    pub utc_offset_hours: i32,
+    pub config: AfterHoursConfig,
+}
+
+/// Knobs for the contribution streak stat, bundling the shared UTC offset
+/// with its own grace-rule config. `None` in [`build_model`] skips computing
+/// it entirely, since it costs an extra contribution-calendar query.
+pub struct StreakOptions {
+    pub utc_offset_hours: i32,
+    pub config: StreakConfig,
+}
+
+/// Everything a [`Renderer`] needs to produce a card, decoupled from the
+/// theme so the same model can be painted under several themes.
+pub struct RenderModel {
+    pub stats: Stats,
+    pub age: Option<String>,
+    pub trophies: Vec<Trophy>,
+    /// Top co-author handles, most frequent first. Always empty unless
+    /// opted into via [`build_model`]'s `show_collaborators` flag.
+    pub collaborators: Vec<String>,
+    /// Career "mini-CV" entries from `Config::timeline`, in configured
+    /// order. Always empty unless configured — see [`crate::timeline`].
+    pub timeline: Vec<TimelineEntry>,
+    /// Skill/level pairs from `Config::skills`, in configured order. Always
+    /// empty unless configured — see [`crate::skills`].
+    pub skills: Vec<SkillEntry>,
+    /// Commit/PR/issue/review split for the "Contribution mix" bar. Always
+    /// fetched, unlike `timeline`/`skills` — see [`crate::contribution_mix`].
+    pub contribution_mix: ContributionMix,
+    /// Cumulative "Stars over time" points, oldest first. Empty unless
+    /// opted into via [`build_model`]'s `show_star_history` flag, since
+    /// paging stargazer history is expensive — see [`crate::star_history`].
+    pub star_history: Vec<StarHistoryPoint>,
+    /// The user's most-starred owned repo for the "Spotlight" box. `None`
+    /// unless opted into via [`build_model`]'s `show_spotlight` flag, or if
+    /// the user owns no repositories — see [`crate::spotlight`].
+    pub spotlight: Option<SpotlightRepo>,
+    /// Open-issue label counts from `Config::maintained_repos`, in
+    /// configured order. Always empty unless configured — see
+    /// [`crate::maintainer`].
+    pub maintainer_dashboard: Vec<MaintainedRepoLabels>,
+    /// Per-year contribution totals back to account creation, oldest first.
+    /// Empty unless opted into via [`build_model`]'s `show_contribution_history`
+    /// flag, since it costs one query per year — see
+    /// [`crate::contribution_history`].
+    pub contribution_history: Vec<YearlyContributions>,
+    /// `key: value` rows from `Config::custom_command`'s external command
+    /// for the optional "Custom" section, in the order the command printed
+    /// them. Always empty unless configured — see [`crate::custom_section`].
+    pub custom_rows: Vec<(String, String)>,
+    /// Byte counts per language across owned, non-fork repos, for the "Top
+    /// Languages" section. Empty unless opted into via [`build_model`]'s
+    /// `show_top_languages` flag, since it costs one extra paged query — see
+    /// [`crate::top_languages`].
+    pub top_languages: Vec<(String, u64)>,
+    pub options: LayoutOptions,
+}
+
+impl RenderModel {
+    /// Derives the theme- and format-independent `sections → rows → typed
+    /// values` view of this model, e.g. for `--dump-model` JSON output,
+    /// user templating, or unit-testing row content without going anywhere
+    /// near SVG string emission.
+    pub fn to_sections(&self) -> Vec<Section> {
+        let mut rows = Vec::new();
+        if let Some(age) = &self.age {
+            rows.push(ModelRow { label: "Uptime".to_string(), value: Value::Text(age.clone()) });
+        }
+        rows.push(ModelRow { label: "Repos".to_string(), value: Value::Number(self.stats.repos as f64) });
+        rows.push(ModelRow { label: "Stars".to_string(), value: Value::Number(self.stats.stars as f64) });
+        rows.push(ModelRow { label: "Followers".to_string(), value: Value::Number(self.stats.followers as f64) });
+        rows.push(ModelRow {
+            label: "Lines of code".to_string(),
+            value: Value::LinesOfCode {
+                total: self.stats.loc_total(),
+                added: self.stats.loc_add,
+                removed: self.stats.loc_del,
+            },
+        });
+        if let Some(hours) = self.stats.median_issue_response_hours {
+            rows.push(ModelRow { label: "Median issue response".to_string(), value: Value::Number(hours) });
+        }
+        if let Some(share) = self.stats.after_hours_share {
+            rows.push(ModelRow { label: "After-hours coder".to_string(), value: Value::Number(share) });
+        }
+        if let Some(quote) = &self.stats.quote {
+            rows.push(ModelRow { label: "Quote".to_string(), value: Value::Text(quote.clone()) });
+        }
+        if let Some(weather) = &self.stats.weather {
+            rows.push(ModelRow { label: "Weather".to_string(), value: Value::Text(weather.clone()) });
+        }
+        if let Some(views) = self.stats.profile_views {
+            rows.push(ModelRow { label: "Profile views".to_string(), value: Value::Number(views as f64) });
+        }
+        if let Some(status) = &self.stats.status {
+            rows.push(ModelRow { label: "Status".to_string(), value: Value::Text(status.clone()) });
+        }
+        if let Some(now_hacking_on) = &self.stats.now_hacking_on {
+            rows.push(ModelRow { label: "Now hacking on".to_string(), value: Value::Text(now_hacking_on.clone()) });
+        }
+        if let Some(commits_by_owner) = &self.stats.commits_by_owner {
+            rows.push(ModelRow { label: "Commits by owner".to_string(), value: Value::Text(commits_by_owner.clone()) });
+        }
+        if let Some(custom_stat) = &self.stats.custom_stat {
+            rows.push(ModelRow { label: "Custom stat".to_string(), value: Value::Text(custom_stat.clone()) });
+        }
+        let mut sections = vec![Section { title: "Stats".to_string(), rows }];
+
+        if !self.trophies.is_empty() {
+            let rows = self
+                .trophies
+                .iter()
+                .map(|t| ModelRow { label: t.label.to_string(), value: Value::Trophy(t.tier) })
+                .collect();
+            sections.push(Section { title: "Trophies".to_string(), rows });
+        }
+
+        if !self.collaborators.is_empty() {
+            let rows = self
+                .collaborators
+                .iter()
+                .map(|login| ModelRow { label: login.clone(), value: Value::Text("collaborator".to_string()) })
+                .collect();
+            sections.push(Section { title: "Frequent collaborators".to_string(), rows });
+        }
+
+        if !self.timeline.is_empty() {
+            let rows = self
+                .timeline
+                .iter()
+                .map(|entry| ModelRow { label: entry.year.clone(), value: Value::Text(entry.role.clone()) })
+                .collect();
+            sections.push(Section { title: "Timeline".to_string(), rows });
+        }
+
+        if !self.skills.is_empty() {
+            let rows = self
+                .skills
+                .iter()
+                .map(|entry| ModelRow { label: entry.name.clone(), value: Value::Number(entry.level as f64) })
+                .collect();
+            sections.push(Section { title: "Skills".to_string(), rows });
+        }
+
+        if self.contribution_mix.total() > 0 {
+            let rows = vec![
+                ModelRow { label: "Commits".to_string(), value: Value::Number(self.contribution_mix.commits as f64) },
+                ModelRow { label: "Pull requests".to_string(), value: Value::Number(self.contribution_mix.pull_requests as f64) },
+                ModelRow { label: "Issues".to_string(), value: Value::Number(self.contribution_mix.issues as f64) },
+                ModelRow { label: "Reviews".to_string(), value: Value::Number(self.contribution_mix.reviews as f64) },
+            ];
+            sections.push(Section { title: "Contribution mix".to_string(), rows });
+        }
+
+        if !self.star_history.is_empty() {
+            let rows = self
+                .star_history
+                .iter()
+                .map(|point| ModelRow { label: point.date.to_string(), value: Value::Number(point.cumulative as f64) })
+                .collect();
+            sections.push(Section { title: "Star history".to_string(), rows });
+        }
+
+        if let Some(repo) = &self.spotlight {
+            let rows = vec![
+                ModelRow { label: "Name".to_string(), value: Value::Text(repo.name.clone()) },
+                ModelRow { label: "Stars".to_string(), value: Value::Number(repo.stars as f64) },
+            ];
+            sections.push(Section { title: "Spotlight".to_string(), rows });
+        }
+
+        if !self.maintainer_dashboard.is_empty() {
+            let rows = self
+                .maintainer_dashboard
+                .iter()
+                .map(|entry| ModelRow {
+                    label: entry.repo.clone(),
+                    value: Value::Text(format!("{} bugs, {} enhancements, {} help wanted", entry.bugs, entry.enhancements, entry.help_wanted)),
+                })
+                .collect();
+            sections.push(Section { title: "Maintainer dashboard".to_string(), rows });
+        }
+
+        if !self.contribution_history.is_empty() {
+            let rows = self
+                .contribution_history
+                .iter()
+                .map(|entry| ModelRow { label: entry.year.to_string(), value: Value::Number(entry.total as f64) })
+                .collect();
+            sections.push(Section { title: "Contribution history".to_string(), rows });
+        }
+
+        if !self.top_languages.is_empty() {
+            let rows = self
+                .top_languages
+                .iter()
+                .map(|(name, size)| ModelRow { label: name.clone(), value: Value::Number(*size as f64) })
+                .collect();
+            sections.push(Section { title: "Top Languages".to_string(), rows });
+        }
+
+        if !self.custom_rows.is_empty() {
+            let rows = self
+                .custom_rows
+                .iter()
+                .map(|(key, value)| ModelRow { label: key.clone(), value: Value::Text(value.clone()) })
+                .collect();
+            sections.push(Section { title: "Custom".to_string(), rows });
+        }
+        sections
+    }
+}
+
+/// How many handles the "Frequent collaborators" row shows.
+const TOP_COLLABORATORS_LIMIT: usize = 5;
+
+/// Fetches `username`'s stats and assembles a [`RenderModel`], in
+/// third-person mode (no age/"Uptime" row) when `birthday` is `None`. Shared
+/// by the one-shot CLI card render and the on-demand `/card` server endpoint
+/// so both stay in sync as the render pipeline evolves. `show_collaborators`
+/// gates an extra history walk to collect co-author handles, since that walk
+/// costs API calls and surfaces other people's logins, so it's opt-in.
+/// `pipeline` runs over the freshly collected [`Stats`] before trophies or
+/// anything else derives from them — see [`crate::postprocess`]. `quote`
+/// picks a quote for the day (or truly at random) from a configured list —
+/// see [`crate::quote`] — and is `None` if no quote list is configured.
+/// `weather` fetches the current conditions for a configured city from
+/// open-meteo — see [`crate::weather`] — and is likewise `None` if no city
+/// is configured. `status_entries` is evaluated against today's date to
+/// find the currently-active one, if any — see [`crate::status`]. `timeline`
+/// and `skills` are copied straight onto the model as the "Timeline" and
+/// "Skills" sections — see [`crate::timeline`] and [`crate::skills`] —
+/// since, unlike the other optional rows, they're static configuration
+/// rather than something derived from freshly fetched stats. The
+/// "Contribution mix" bar, by contrast, is always fetched fresh alongside
+/// `commits`/`stars` — see [`crate::contribution_mix`]. `show_star_history`
+/// gates paging every top repo's full stargazer list, like
+/// `show_collaborators` gates the co-author walk, since it's by far the
+/// most expensive collector this function can run — see
+/// [`crate::star_history`]. `show_spotlight` gates fetching the user's
+/// most-starred repo for the "Spotlight" box — see [`crate::spotlight`]. The
+/// "Now hacking on" row is likewise always fetched fresh, from the most
+/// recently pushed-to owned repos — see
+/// [`crate::github::GithubClient::currently_working_on`]. The "Commits by
+/// owner" row is likewise always fetched fresh, bucketing commit
+/// contributions by own/org/other repository ownership — see
+/// [`crate::github::GithubClient::commits_by_owner_type`]. `maintained_repos`
+/// is copied from `Config::maintained_repos` and, unlike `timeline`/`skills`,
+/// each entry costs a live query for the "Maintainer dashboard" section —
+/// see [`crate::maintainer`]. `show_contribution_history` gates walking every
+/// year back to account creation for the "Contribution history" table, since
+/// that costs one query per year — see [`crate::contribution_history`].
+/// `loc_commit_cap` bounds how many commits `total_loc` walks per repo,
+/// surfaced back as `Stats::loc_truncated` if any repo hit it — see
+/// [`crate::github::GithubClient::total_loc`]. `skip_loc` bypasses `total_loc`
+/// entirely, for a faster render when the "Lines of code" row isn't wanted —
+/// see `Stats::loc_skipped`. `custom_command` runs a configured external
+/// command for the optional "Custom" section, like `weather` fetching fresh
+/// each render rather than being copied straight from config like
+/// `timeline`/`skills` — `None` (or an unconfigured [`CustomCommandConfig`])
+/// leaves the section empty. See [`crate::custom_section`]. `enable_plugins`
+/// additionally folds in any `halfguru-collector-*` executables found on
+/// `PATH`, appended after `custom_command`'s own rows — see
+/// [`crate::plugins`]. `show_top_languages` opts into the "Top Languages"
+/// section — see [`crate::top_languages`]. `streak` opts into the current/longest
+/// streak rows, since it costs an extra contribution-calendar query — see
+/// [`crate::streak`]. `show_commits_all_time` opts into `Stats::commits_all_time`,
+/// since like `show_contribution_history` it costs one query per year — see
+/// [`crate::github::GithubClient::commit_count_all_time`].
+#[tracing::instrument(skip(client, after_hours, streak, pipeline))]
+pub fn build_model(
+    client: &GithubClient,
+    username: &str,
+    birthday: Option<&str>,
+    show_collaborators: bool,
+    show_star_history: bool,
+    show_spotlight: bool,
+    show_contribution_history: bool,
+    show_top_languages: bool,
+    show_commits_all_time: bool,
+    after_hours: Option<&AfterHoursOptions>,
+    streak: Option<&StreakOptions>,
+    pipeline: &[Box<dyn StatProcessor>],
+    quote: Option<&QuoteConfig>,
+    weather: Option<&WeatherConfig>,
+    status_entries: &[StatusEntry],
+    timeline: &[TimelineEntry],
+    skills: &[SkillEntry],
+    maintained_repos: &[String],
+    loc_commit_cap: Option<u32>,
+    skip_loc: bool,
+    custom_command: Option<&CustomCommandConfig>,
+    enable_plugins: bool,
+) -> Result<RenderModel> {
+    client.verify_user(username)?;
+    let stars = client.star_count(username)?;
+    let commits = client.commit_count(username)?;
+    let contribution_mix = client.contribution_mix(username)?;
+    let currently_working_on = client.currently_working_on(username)?;
+    let commit_ownership = client.commits_by_owner_type(username)?;
+    let followers = client.follower_count(username)?;
+    let repos = client.list_owned_repos(username)?;
+    let (loc_add, loc_del, loc_truncated) =
+        if skip_loc { (0, 0, false) } else { client.total_loc(username, loc_commit_cap)? };
+    let median_issue_response_hours = client.median_issue_response_hours(username)?;
+    let after_hours_share = match after_hours {
+        Some(options) => client.after_hours_share(username, options.utc_offset_hours, &options.config)?,
+        None => None,
+    };
+    let (current_streak, longest_streak) = match streak {
+        Some(options) => {
+            let calendar = client.contribution_calendar(username)?;
+            let streak = crate::streak::compute_streak(&calendar, options.utc_offset_hours, &options.config);
+            (Some(streak.current), Some(streak.longest))
+        }
+        None => (None, None),
+    };
+    let commits_all_time = if show_commits_all_time { Some(client.commit_count_all_time(username)?) } else { None };
+
+    let mut stats = Stats {
+        username: username.to_string(),
+        stars,
+        commits,
+        repos: repos.len() as u32,
+        followers,
+        languages: 0,
+        loc_add,
+        loc_del,
+        loc_truncated,
+        loc_skipped: skip_loc,
+        median_issue_response_hours,
+        after_hours_share,
+        current_streak,
+        longest_streak,
+        commits_all_time,
+        quote: quote.and_then(|config| crate::quote::pick(config, chrono::Utc::now().date_naive()).map(crate::emoji::expand_shortcodes)),
+        weather: weather.and_then(crate::weather::fetch),
+        profile_views: None,
+        status: crate::status::active(status_entries, chrono::Utc::now().date_naive()).map(crate::emoji::expand_shortcodes),
+        now_hacking_on: (!currently_working_on.is_empty()).then(|| currently_working_on.join(", ")),
+        commits_by_owner: (commit_ownership.own + commit_ownership.org + commit_ownership.other > 0)
+            .then(|| format!("{} own, {} org, {} other", commit_ownership.own, commit_ownership.org, commit_ownership.other)),
+        custom_stat: None,
+    };
+    crate::postprocess::run(&mut stats, pipeline);
+
+    let age = birthday
+        .map(|birthday| {
+            let birthday = chrono::NaiveDate::parse_from_str(birthday, "%Y-%m-%d")
+                .map_err(|e| Error::Other(format!("invalid --birthday {birthday:?}: {e}")))?;
+            Ok(crate::age::calculate_age(birthday))
+        })
+        .transpose()?;
+    let trophies = crate::trophies::compute_trophies(&stats);
+    let collaborators = if show_collaborators { client.top_collaborators(username, TOP_COLLABORATORS_LIMIT)? } else { Vec::new() };
+    let star_history = if show_star_history { client.star_history(username)? } else { Vec::new() };
+    let spotlight = if show_spotlight { client.spotlight_repo(username)? } else { None };
+    let maintainer_dashboard = client.maintainer_dashboard(maintained_repos)?;
+    let contribution_history = if show_contribution_history { client.contribution_history(username)? } else { Vec::new() };
+    let top_languages = if show_top_languages { client.language_totals(username)? } else { Vec::new() };
+    let mut custom_rows = custom_command.map(crate::custom_section::run).unwrap_or_default();
+    if enable_plugins {
+        custom_rows.extend(crate::plugins::collect_all());
+    }
+    Ok(RenderModel {
+        stats,
+        age,
+        trophies,
+        collaborators,
+        timeline: timeline.to_vec(),
+        skills: skills.to_vec(),
+        contribution_mix,
+        star_history,
+        spotlight,
+        maintainer_dashboard,
+        contribution_history,
+        custom_rows,
+        top_languages,
+        options: LayoutOptions::default(),
+    })
+}
+
+/// A named group of rows, e.g. "Stats" or "Trophies".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Section {
+    pub title: String,
+    pub rows: Vec<ModelRow>,
+}
+
+/// One row's label and typed value, independent of how it ends up rendered.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelRow {
+    pub label: String,
+    pub value: Value,
+}
+
+/// A row's value, tagged by kind so a template or JSON consumer can branch
+/// on shape instead of parsing a pre-formatted string.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum Value {
+    Text(String),
+    Number(f64),
+    LinesOfCode { total: i64, added: u64, removed: u64 },
+    Trophy(crate::trophies::Tier),
+}
+
+/// One rendered output, e.g. an SVG document ready to write to disk.
+pub struct Artifact {
+    pub filename: String,
+    pub content: String,
+}
+
+/// Something that can turn a [`RenderModel`] into one or more [`Artifact`]s.
+pub trait Renderer {
+    fn render(&self, model: &RenderModel, theme: &Theme) -> Vec<Artifact>;
+}
+
+/// The default renderer, producing the profile card SVG.
+pub struct SvgRenderer;
+
+impl Renderer for SvgRenderer {
+    fn render(&self, model: &RenderModel, theme: &Theme) -> Vec<Artifact> {
+        let svg = svg::generate_svg(
+            &model.stats,
+            *theme,
+            model.age.as_deref(),
+            &model.trophies,
+            &model.collaborators,
+            &model.timeline,
+            &model.skills,
+            &model.contribution_mix,
+            &model.star_history,
+            &model.spotlight,
+            &model.maintainer_dashboard,
+            &model.contribution_history,
+            &model.custom_rows,
+            &model.top_languages,
+            model.options.clone(),
+        );
+        vec![Artifact { filename: "card.svg".to_string(), content: svg }]
+    }
+}
+
+impl SvgRenderer {
+    /// Renders `model` under each of `themes`, sharing the theme-independent
+    /// layout pass across all of them — see [`svg::generate_svg_multi_theme`].
+    /// Returns raw SVG content in the same order as `themes`; unlike
+    /// [`Renderer::render`], callers pick their own output filenames since
+    /// e.g. `--dual-theme` names them after the theme, not `"card.svg"`.
+    pub fn render_multi(&self, model: &RenderModel, themes: &[Theme]) -> Vec<String> {
+        svg::generate_svg_multi_theme(
+            &model.stats,
+            themes,
+            model.age.as_deref(),
+            &model.trophies,
+            &model.collaborators,
+            &model.timeline,
+            &model.skills,
+            &model.contribution_mix,
+            &model.star_history,
+            &model.spotlight,
+            &model.maintainer_dashboard,
+            &model.contribution_history,
+            &model.custom_rows,
+            &model.top_languages,
+            model.options.clone(),
+        )
+    }
+
+    /// Like [`Renderer::render`], but shares `cache` across calls for the
+    /// same `username` so on-demand server renders reuse unchanged
+    /// components instead of re-rendering them — see [`svg::FragmentCache`].
+    /// Not part of the [`Renderer`] trait since a one-shot CLI render has no
+    /// cache to share across calls.
+    pub fn render_cached(&self, model: &RenderModel, theme: &Theme, username: &str, cache: &svg::FragmentCache) -> Vec<Artifact> {
+        let svg = svg::generate_svg_cached(
+            &model.stats,
+            *theme,
+            model.age.as_deref(),
+            &model.trophies,
+            &model.collaborators,
+            &model.timeline,
+            &model.skills,
+            &model.contribution_mix,
+            &model.star_history,
+            &model.spotlight,
+            &model.maintainer_dashboard,
+            &model.contribution_history,
+            &model.custom_rows,
+            &model.top_languages,
+            model.options.clone(),
+            username,
+            cache,
+        );
+        vec![Artifact { filename: "card.svg".to_string(), content: svg }]
+    }
+}
@@ -0,0 +1,66 @@
+//! Renders a themed card for a single repository (see the `repo-card`
+//! subcommand in `main.rs`) — the repo-level analogue of the profile card.
+
+use crate::github::RepoInfo;
+use crate::svg::Theme;
+
+const WIDTH: u32 = 380;
+const HEIGHT: u32 = 130;
+
+const LANGUAGE_BAR_WIDTH: u32 = 340;
+
+/// A fixed-length bar under the title showing `primary_language` as a single
+/// colored segment, mirroring the language bar on github.com repo pages,
+/// with a [`crate::svg::render_legend`] swatch and label underneath. Colored
+/// via [`crate::linguist`] so it matches the color github.com itself uses
+/// for that language. The single entry is always 100% since `RepoInfo` only
+/// tracks a repo's primary language, not a full per-language breakdown.
+fn language_bar(language: Option<&str>) -> String {
+    match language {
+        Some(lang) => {
+            let color = crate::linguist::color_for(lang);
+            let bar = format!(r#"<rect x="20" y="45" width="{LANGUAGE_BAR_WIDTH}" height="6" rx="3" fill="{color}"/>"#);
+            let entry = crate::svg::LegendEntry { label: lang.to_string(), color, share: 1.0 };
+            let legend = crate::svg::render_legend(&[entry], 20, 65, 1, r#"class="row""#);
+            format!("{bar}\n    {legend}")
+        }
+        None => String::new(),
+    }
+}
+
+pub fn render_repo_card(info: &RepoInfo, theme: Theme) -> String {
+    let colors = theme.colors();
+    let release_row = match &info.latest_release {
+        Some(tag) => format!(r#"<text x="20" y="115" class="row">Latest release: {}</text>"#, crate::ascii::escape_xml_text(tag)),
+        None => String::new(),
+    };
+
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{HEIGHT}" viewBox="0 0 {width} {HEIGHT}">
+  <style>
+    .card {{ font: 400 14px 'Segoe UI', Ubuntu, sans-serif; }}
+    .title {{ font: 600 16px 'Segoe UI', Ubuntu, sans-serif; fill: {title}; }}
+    .row {{ fill: {text}; }}
+  </style>
+  <rect x="0.5" y="0.5" rx="4.5" width="{width}" height="{height}" fill="{background}" stroke="{border}"/>
+  <g class="card">
+    <text x="20" y="30" class="title">{name}</text>
+    {language_bar}
+    <text x="20" y="90" class="row">★ {stars}    ⑂ {forks}    ⊙ {open_issues} open issues</text>
+    {release_row}
+  </g>
+</svg>"#,
+        width = WIDTH - 1,
+        height = HEIGHT - 1,
+        title = colors.title,
+        text = colors.text,
+        background = colors.background,
+        border = colors.border,
+        name = crate::ascii::escape_xml_text(&info.name),
+        language_bar = language_bar(info.primary_language.as_deref()),
+        stars = info.stars,
+        forks = info.forks,
+        open_issues = info.open_issues,
+        release_row = release_row,
+    )
+}
@@ -0,0 +1,85 @@
+//! JSON Schema for the [`crate::config::Config`] file, for `halfguru schema`
+//! and editor validation/autocomplete on the growing configuration surface.
+//!
+//! This is hand-maintained rather than derived from the `Config` struct via
+//! `schemars`: that crate would be the natural fit, but this tree has no
+//! `Cargo.toml` to add it to, so the schema below is written out by hand and
+//! needs updating alongside `Config` itself — a real derive would be worth
+//! switching to the moment this crate has a manifest again. Most nested
+//! config types (`QuoteConfig`, `WeatherConfig`, ...) are left as untyped
+//! objects rather than fully expanded; only `custom_theme` and `custom_stat`
+//! list their fields. That keeps this maintainable as a hand-written
+//! artifact instead of silently drifting out of sync on every field added to
+//! them.
+
+use serde_json::{json, Value};
+
+/// Builds the schema fresh each call — it's static data, but small enough
+/// that caching it isn't worth the complexity.
+pub fn generate() -> Value {
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "halfguru config",
+        "description": "On-disk configuration for `halfguru server`. See Config in src/config.rs for the authoritative field list.",
+        "type": "object",
+        "additionalProperties": false,
+        "properties": {
+            "theme": { "type": "string", "enum": ["default", "dark", "dracula", "gruvbox", "catppuccin", "solarized"], "default": "default" },
+            "custom_theme": {
+                "type": ["object", "null"],
+                "description": "See CustomThemeConfig. Overrides `theme` when set.",
+                "properties": {
+                    "bg": { "type": "string" },
+                    "text": { "type": "string" },
+                    "key": { "type": "string" },
+                    "value": { "type": "string" },
+                    "cc": { "type": "string" },
+                    "add": { "type": "string" },
+                    "del": { "type": "string" }
+                },
+                "required": ["bg", "text", "key", "value", "cc", "add", "del"],
+                "default": null
+            },
+            "ascii_art_file": { "type": ["string", "null"], "description": "Path to a text file replacing the default left-column ASCII art.", "default": null },
+            "show_avatar": { "type": "boolean", "description": "Show the user's GitHub avatar in the left column instead of ASCII art.", "default": false },
+            "palette": { "type": "string", "enum": ["", "standard", "deuteranopia", "protanopia"], "default": "" },
+            "private_contributions": { "type": "string", "enum": ["", "hidden", "fold", "show"], "default": "" },
+            "excluded_repos": { "type": "array", "items": { "type": "string" }, "default": [] },
+            "allowed_users": { "type": "array", "items": { "type": "string" }, "default": [] },
+            "streak": { "type": "object", "description": "See StreakConfig." },
+            "utc_offset_hours": { "type": "integer", "default": 0 },
+            "show_collaborators": { "type": "boolean", "default": false },
+            "show_star_history": { "type": "boolean", "default": false },
+            "show_spotlight": { "type": "boolean", "default": false },
+            "show_contribution_history": { "type": "boolean", "default": false },
+            "show_top_languages": { "type": "boolean", "default": false },
+            "show_streak": { "type": "boolean", "default": false },
+            "show_commits_all_time": { "type": "boolean", "default": false },
+            "show_after_hours": { "type": "boolean", "default": false },
+            "after_hours": { "type": "object", "description": "See AfterHoursConfig." },
+            "stat_cap": { "type": ["integer", "null"], "default": null },
+            "stat_floor": { "type": ["integer", "null"], "default": null },
+            "loc_commit_cap": { "type": ["integer", "null"], "default": null },
+            "skip_loc": { "type": "boolean", "default": false },
+            "locale": { "type": "string", "enum": ["", "de-de", "ar-sa", "he-il"], "default": "" },
+            "quote": { "type": "object", "description": "See QuoteConfig." },
+            "weather": { "type": "object", "description": "See WeatherConfig." },
+            "status": { "type": "array", "items": { "type": "object" }, "description": "See StatusEntry.", "default": [] },
+            "timeline": { "type": "array", "items": { "type": "object" }, "description": "See TimelineEntry.", "default": [] },
+            "skills": { "type": "array", "items": { "type": "object" }, "description": "See SkillEntry.", "default": [] },
+            "maintained_repos": { "type": "array", "items": { "type": "string" }, "default": [] },
+            "custom_command": { "type": "object", "description": "See CustomCommandConfig." },
+            "custom_stat": {
+                "type": ["object", "null"],
+                "description": "See FormulaStat.",
+                "properties": {
+                    "label": { "type": "string" },
+                    "expression": { "type": "string" }
+                },
+                "required": ["label", "expression"],
+                "default": null
+            },
+            "enable_plugins": { "type": "boolean", "default": false }
+        }
+    })
+}
@@ -0,0 +1,51 @@
+//! Resolves credentials by name instead of each integration doing its own
+//! `std::env::var(...)` lookup — replaces the single hardcoded `ACCESS_TOKEN`
+//! read that used to live in `main.rs`. Tries, in order: an env var, a
+//! `.env` file in the current directory, and the OS keychain (via the
+//! `keyring` crate's per-platform Keychain/Credential Manager/Secret Service
+//! backends), so a credential can live wherever fits the deployment — CI
+//! secrets, local dev, or a desktop keychain — without the caller caring
+//! which.
+
+use std::collections::HashMap;
+
+/// Keychain service name every halfguru secret is stored under.
+const SERVICE: &str = "halfguru";
+
+/// Resolves `name` (e.g. `"ACCESS_TOKEN"`) from the environment, falling
+/// back to a `.env` file in the current directory, then the OS keychain.
+/// `None` if none of the three have it.
+pub fn resolve(name: &str) -> Option<String> {
+    resolve_from(name, ".env")
+}
+
+/// [`resolve`], with the `.env` path overridable for testing.
+fn resolve_from(name: &str, dotenv_path: &str) -> Option<String> {
+    non_empty(std::env::var(name).ok())
+        .or_else(|| non_empty(read_dotenv(dotenv_path).get(name).cloned()))
+        .or_else(|| non_empty(keyring::Entry::new(SERVICE, name).ok().and_then(|entry| entry.get_password().ok())))
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|v| !v.is_empty())
+}
+
+/// Parses a `.env`-style file (`KEY=value` per line, `#` comments and blank
+/// lines ignored, surrounding quotes stripped) into a map. A missing file or
+/// unparseable lines just don't contribute entries, rather than failing the
+/// whole resolution chain over an optional file.
+fn read_dotenv(path: &str) -> HashMap<String, String> {
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return HashMap::new();
+    };
+    contents
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                return None;
+            }
+            line.split_once('=').map(|(key, value)| (key.trim().to_string(), value.trim().trim_matches('"').to_string()))
+        })
+        .collect()
+}
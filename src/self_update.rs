@@ -0,0 +1,114 @@
+//! `halfguru self-update`: checks whether the repo's latest GitHub release
+//! differs from the running version, downloads the matching platform binary, checks it against
+//! the release's own `.sha256` asset, and replaces the running executable —
+//! for people running the binary standalone on a home server instead of
+//! through CI.
+//!
+//! The checksum comparison only catches transport corruption, not a
+//! malicious or tampered release: the `.sha256` asset is fetched from the
+//! same GitHub release as the binary, so anyone able to publish a bad binary
+//! (a compromised release pipeline, a compromised maintainer account) can
+//! publish a matching checksum right alongside it. Treat this as a sanity
+//! check, not a substitute for release-signing.
+
+use crate::error::{Error, Result};
+use reqwest::blocking::Client;
+use reqwest::header::USER_AGENT;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::io::Write;
+
+const RELEASES_LATEST_URL: &str = "https://api.github.com/repos/halfguru/halfguru/releases/latest";
+
+#[derive(Deserialize)]
+struct Release {
+    tag_name: String,
+    assets: Vec<Asset>,
+}
+
+#[derive(Deserialize)]
+struct Asset {
+    name: String,
+    browser_download_url: String,
+}
+
+/// The platform binary's asset name, e.g. `halfguru-x86_64-unknown-linux-gnu`
+/// (`.exe` appended separately for Windows).
+fn asset_name() -> String {
+    let os_suffix = match std::env::consts::OS {
+        "windows" => "pc-windows-msvc",
+        "macos" => "apple-darwin",
+        _ => "unknown-linux-gnu",
+    };
+    format!("halfguru-{}-{os_suffix}", std::env::consts::ARCH)
+}
+
+/// Checks the latest GitHub release against `current_version`; if its
+/// version differs (versions aren't ordered, so any difference counts as an
+/// update), downloads the matching binary, checks it against the release's
+/// `.sha256` asset, and swaps it in for the running executable.
+pub fn run(current_version: &str) -> Result<()> {
+    let http = Client::new();
+    let release: Release = http.get(RELEASES_LATEST_URL).header(USER_AGENT, "halfguru").send()?.json()?;
+
+    let latest_version = release.tag_name.trim_start_matches('v');
+    if latest_version == current_version {
+        println!("already up to date ({current_version})");
+        return Ok(());
+    }
+
+    let binary_name = if cfg!(windows) { format!("{}.exe", asset_name()) } else { asset_name() };
+    let binary_asset = release
+        .assets
+        .iter()
+        .find(|a| a.name == binary_name)
+        .ok_or_else(|| Error::Other(format!("no release asset for this platform ({binary_name})")))?;
+    let checksum_asset = release
+        .assets
+        .iter()
+        .find(|a| a.name == format!("{binary_name}.sha256"))
+        .ok_or_else(|| Error::Other(format!("no checksum asset for {binary_name}")))?;
+
+    let bytes = http.get(&binary_asset.browser_download_url).header(USER_AGENT, "halfguru").send()?.bytes()?;
+    let checksum_body = http.get(&checksum_asset.browser_download_url).header(USER_AGENT, "halfguru").send()?.text()?;
+    let expected_checksum = checksum_body.split_whitespace().next().unwrap_or_default();
+
+    let mut hasher = Sha256::new();
+    hasher.update(&bytes);
+    let actual_checksum = format!("{:x}", hasher.finalize());
+    if actual_checksum != expected_checksum {
+        return Err(Error::Other(format!(
+            "checksum mismatch for {binary_name}: release published {expected_checksum}, downloaded {actual_checksum}"
+        )));
+    }
+
+    replace_running_executable(&bytes)?;
+    println!("updated to {}", release.tag_name);
+    Ok(())
+}
+
+/// Writes `bytes` to a staged file next to the running executable, then
+/// swaps it in. Renaming straight over the running executable works on
+/// Unix but not on Windows, which keeps the old binary locked while this
+/// process is executing from it — so the old binary is moved aside first
+/// on every platform, then removed once the swap succeeds.
+fn replace_running_executable(bytes: &[u8]) -> Result<()> {
+    let current_exe = std::env::current_exe()?;
+    let staged = current_exe.with_extension("new");
+    {
+        let mut file = std::fs::File::create(&staged)?;
+        file.write_all(bytes)?;
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(0o755))?;
+    }
+
+    let old_exe = current_exe.with_extension("old");
+    let _ = std::fs::remove_file(&old_exe);
+    std::fs::rename(&current_exe, &old_exe)?;
+    std::fs::rename(&staged, &current_exe)?;
+    let _ = std::fs::remove_file(&old_exe);
+    Ok(())
+}
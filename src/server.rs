@@ -0,0 +1,377 @@
+//! Long-running daemon mode: watches the config file and keeps a shared,
+//! in-memory copy up to date so cards re-render with the new theme/rows on
+//! the next tick, without restarting the process. `excluded_repos` is loaded
+//! with the rest of the config, but no render reads it.
+
+use crate::config::{self, Config};
+use crate::github::GithubClient;
+use crate::postprocess::StatProcessor;
+use crate::render::SvgRenderer;
+use crate::svg::FragmentCache;
+use crate::visitors::VisitorCounter;
+use notify::{RecursiveMode, Watcher};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{IpAddr, TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+/// One HTTP response: status line, content type, body, and cache-validation
+/// headers. `etag`/`last_modified` are only set on cacheable (SVG) bodies —
+/// GitHub's camo proxy and browsers use them to skip re-fetching a card that
+/// hasn't changed since the last request.
+struct Response {
+    status: &'static str,
+    content_type: &'static str,
+    body: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+impl Response {
+    fn json(status: &'static str, body: serde_json::Value) -> Self {
+        Self { status, content_type: "application/json", body: body.to_string(), etag: None, last_modified: None }
+    }
+
+    fn svg(body: String) -> Self {
+        Self {
+            status: "200 OK",
+            content_type: "image/svg+xml",
+            etag: Some(etag_for(&body)),
+            last_modified: Some(http_date_now()),
+            body,
+        }
+    }
+
+    /// A cache-hit response for a request whose `If-None-Match` matched.
+    fn not_modified(etag: String) -> Self {
+        Self { status: "304 Not Modified", content_type: "image/svg+xml", body: String::new(), etag: Some(etag), last_modified: Some(http_date_now()) }
+    }
+}
+
+/// Content-addressed ETag for `body`, quoted per RFC 7232.
+fn etag_for(body: &str) -> String {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    body.hash(&mut hasher);
+    format!("\"{:x}\"", hasher.finish())
+}
+
+/// Current time formatted as an HTTP-date (RFC 7231 `IMF-fixdate`), e.g.
+/// `Sun, 06 Nov 1994 08:49:37 GMT`.
+fn http_date_now() -> String {
+    chrono::Utc::now().format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+/// Starts watching `config_path`, updating `shared` in place on every change.
+/// The returned `Watcher` must be kept alive for the duration of the watch —
+/// dropping it stops delivery.
+pub fn watch_config(config_path: PathBuf, shared: Arc<RwLock<Config>>) -> notify::Result<impl Watcher> {
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        if event.is_err() {
+            return;
+        }
+        match config::load(&config_path) {
+            Ok(new_config) => {
+                if let Ok(mut guard) = shared.write() {
+                    *guard = new_config;
+                }
+            }
+            Err(e) => eprintln!("config reload failed, keeping previous config: {e}"),
+        }
+    })?;
+    watcher.watch(&config_path, RecursiveMode::NonRecursive)?;
+    Ok(watcher)
+}
+
+/// Set once the first card render has succeeded. `/readyz` reports 503 until
+/// then, so a load balancer doesn't route traffic at a pod that's still
+/// fetching its first set of stats.
+pub type Readiness = Arc<AtomicBool>;
+
+/// Guards against a publicly-exposed health/readiness endpoint being hammered
+/// into draining the owner's GitHub API quota: an optional shared-secret
+/// token and a per-source-IP request cap.
+#[derive(Debug, Clone)]
+pub struct HealthServerOptions {
+    /// When set, every request must include a matching `?token=` query
+    /// parameter or gets a 401.
+    pub auth_token: Option<String>,
+    /// Requests allowed per source IP in a fixed one-minute window (reset
+    /// on the first request more than 60s after the window opened) before
+    /// a 429; `0` disables the limiter.
+    pub rate_limit_per_minute: u32,
+}
+
+impl Default for HealthServerOptions {
+    fn default() -> Self {
+        Self { auth_token: None, rate_limit_per_minute: 60 }
+    }
+}
+
+/// Fixed-window per-IP request counter backing [`HealthServerOptions::rate_limit_per_minute`].
+#[derive(Default)]
+struct RateLimiter {
+    windows: Mutex<HashMap<IpAddr, (Instant, u32)>>,
+}
+
+impl RateLimiter {
+    /// Returns `true` if `ip` is still within its budget for the current window.
+    fn allow(&self, ip: IpAddr, limit: u32) -> bool {
+        if limit == 0 {
+            return true;
+        }
+        let mut windows = self.windows.lock().expect("rate limiter lock poisoned");
+        let now = Instant::now();
+        let (window_start, count) = windows.entry(ip).or_insert((now, 0));
+        if now.duration_since(*window_start) > Duration::from_secs(60) {
+            *window_start = now;
+            *count = 0;
+        }
+        *count += 1;
+        *count <= limit
+    }
+}
+
+/// Starts a minimal HTTP listener on `addr` (e.g. `"0.0.0.0:8080"`) serving
+/// `/healthz`, `/readyz` (each with the token's remaining GraphQL quota in
+/// the JSON body), and `/card?user=<login>` (renders that user's card as SVG
+/// on demand). `owner` always passes the `/card` allowlist; anyone else needs
+/// to be in the live `config`'s `allowed_users`. Requests are subject to
+/// `options`' shared-secret auth and per-IP rate limit before any route
+/// runs. Runs on a dedicated thread; failures to bind are logged and treated
+/// as "endpoints disabled" rather than fatal, since a probe/serving endpoint
+/// isn't worth taking the whole daemon down over.
+pub fn serve_health(
+    addr: &str,
+    client: GithubClient,
+    ready: Readiness,
+    options: HealthServerOptions,
+    owner: String,
+    config: Arc<RwLock<Config>>,
+) -> std::io::Result<()> {
+    let listener = TcpListener::bind(addr)?;
+    let limiter = Arc::new(RateLimiter::default());
+    let fragment_cache = Arc::new(FragmentCache::default());
+    let visitor_counter = Arc::new(VisitorCounter::load(client.cache_dir()));
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            handle_health_request(stream, &client, &ready, &options, &limiter, &owner, &config, &fragment_cache, &visitor_counter);
+        }
+    });
+    Ok(())
+}
+
+fn handle_health_request(
+    mut stream: TcpStream,
+    client: &GithubClient,
+    ready: &Readiness,
+    options: &HealthServerOptions,
+    limiter: &RateLimiter,
+    owner: &str,
+    config: &Arc<RwLock<Config>>,
+    fragment_cache: &Arc<FragmentCache>,
+    visitor_counter: &Arc<VisitorCounter>,
+) {
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).is_err() {
+        return;
+    }
+    let target = request_line.split_whitespace().nth(1).unwrap_or("");
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let if_none_match = read_header(&mut reader, "if-none-match");
+
+    let peer_ip = stream.peer_addr().map(|addr| addr.ip()).ok();
+    let mut response = if peer_ip.is_some_and(|ip| !limiter.allow(ip, options.rate_limit_per_minute)) {
+        Response::json("429 Too Many Requests", serde_json::json!({ "error": "rate limit exceeded" }))
+    } else if !token_matches(query, options.auth_token.as_deref()) {
+        Response::json("401 Unauthorized", serde_json::json!({ "error": "missing or invalid token" }))
+    } else {
+        match path {
+            "/card" => route_card_request(query, client, owner, config, fragment_cache, visitor_counter),
+            _ => route_health_request(path, client, ready),
+        }
+    };
+
+    if let (Some(etag), Some(if_none_match)) = (&response.etag, &if_none_match) {
+        if etag == if_none_match {
+            response = Response::not_modified(etag.clone());
+        }
+    }
+
+    let mut head = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+        response.status,
+        response.content_type,
+        response.body.len()
+    );
+    if let Some(etag) = &response.etag {
+        head.push_str(&format!("ETag: {etag}\r\n"));
+    }
+    if let Some(last_modified) = &response.last_modified {
+        head.push_str(&format!("Last-Modified: {last_modified}\r\n"));
+    }
+    head.push_str("\r\n");
+    let _ = stream.write_all(head.as_bytes());
+    let _ = stream.write_all(response.body.as_bytes());
+}
+
+/// Reads request headers off `reader` until the blank line terminating them,
+/// returning the value of `name` (case-insensitive) if present.
+fn read_header(reader: &mut BufReader<&TcpStream>, name: &str) -> Option<String> {
+    let mut found = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).is_err() || line == "\r\n" || line.is_empty() {
+            break;
+        }
+        if let Some((key, value)) = line.split_once(':') {
+            if key.trim().eq_ignore_ascii_case(name) {
+                found = Some(value.trim().to_string());
+            }
+        }
+    }
+    found
+}
+
+/// `expected` of `None` means auth is disabled and every request passes.
+fn token_matches(query: &str, expected: Option<&str>) -> bool {
+    let Some(expected) = expected else {
+        return true;
+    };
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .any(|(key, value)| key == "token" && value == expected)
+}
+
+fn query_param<'a>(query: &'a str, key: &str) -> Option<&'a str> {
+    query.split('&').filter_map(|pair| pair.split_once('=')).find(|(k, _)| *k == key).map(|(_, v)| v)
+}
+
+fn route_health_request(path: &str, client: &GithubClient, ready: &Readiness) -> Response {
+    let quota = match client.rate_limit() {
+        Ok(rate_limit) => serde_json::to_value(rate_limit).unwrap_or(serde_json::Value::Null),
+        Err(e) => serde_json::json!({ "error": e.to_string() }),
+    };
+
+    match path {
+        "/healthz" => Response::json("200 OK", serde_json::json!({ "status": "ok", "rate_limit": quota })),
+        "/readyz" if ready.load(Ordering::Relaxed) => {
+            Response::json("200 OK", serde_json::json!({ "status": "ready", "rate_limit": quota }))
+        }
+        "/readyz" => Response::json("503 Service Unavailable", serde_json::json!({ "status": "not ready", "rate_limit": quota })),
+        _ => Response::json("404 Not Found", serde_json::json!({ "error": "not found" })),
+    }
+}
+
+/// Renders `?user=<login>` on demand, restricted to `owner` plus whoever is
+/// currently on `config`'s `allowed_users` — otherwise a publicly-exposed
+/// `/card` becomes a free stats API for the whole internet.
+fn route_card_request(
+    query: &str,
+    client: &GithubClient,
+    owner: &str,
+    config: &Arc<RwLock<Config>>,
+    fragment_cache: &FragmentCache,
+    visitor_counter: &VisitorCounter,
+) -> Response {
+    let username = query_param(query, "user").unwrap_or(owner);
+    let config = config.read().expect("config lock poisoned");
+    if !config.user_allowed(username, owner) {
+        return Response::json("403 Forbidden", serde_json::json!({ "error": format!("{username} is not on the allowlist") }));
+    }
+    let theme = config.theme();
+    let custom_theme = config.custom_theme_colors();
+    let palette = config.palette();
+    let stat_limits = config.stat_limits();
+    let private_contributions = config.private_contributions_mode();
+    let locale = config.locale();
+    for warning in crate::contrast::validate_theme(custom_theme.as_ref().unwrap_or(&theme.colors())) {
+        eprintln!("warning: {warning}");
+    }
+    let ascii_art = match &config.ascii_art_file {
+        Some(path) => std::fs::read_to_string(path).unwrap_or_else(|e| {
+            eprintln!("warning: ascii_art_file {path} unreadable ({e}), using the default art");
+            crate::ascii::DEFAULT_ASCII_ART.to_string()
+        }),
+        None => crate::ascii::DEFAULT_ASCII_ART.to_string(),
+    };
+    for warning in crate::ascii::validate(&ascii_art) {
+        eprintln!("warning: {warning}");
+    }
+    let show_collaborators = config.show_collaborators;
+    let show_star_history = config.show_star_history;
+    let show_spotlight = config.show_spotlight;
+    let show_contribution_history = config.show_contribution_history;
+    let show_top_languages = config.show_top_languages;
+    let show_commits_all_time = config.show_commits_all_time;
+    let show_avatar = config.show_avatar;
+    let after_hours = config
+        .show_after_hours
+        .then(|| crate::render::AfterHoursOptions { utc_offset_hours: config.utc_offset_hours, config: config.after_hours.clone() });
+    let streak = config.show_streak.then(|| crate::render::StreakOptions { utc_offset_hours: config.utc_offset_hours, config: config.streak.clone() });
+    let quote_config = config.quote.clone();
+    let weather_config = config.weather.clone();
+    let status_entries = config.status.clone();
+    let timeline = config.timeline.clone();
+    let skills = config.skills.clone();
+    let maintained_repos = config.maintained_repos.clone();
+    let loc_commit_cap = config.loc_commit_cap;
+    let skip_loc = config.skip_loc;
+    let custom_command = config.custom_command.clone();
+    let enable_plugins = config.enable_plugins;
+    let pipeline: Vec<Box<dyn StatProcessor>> = config.custom_stat.clone().into_iter().map(|formula| Box::new(formula) as Box<dyn StatProcessor>).collect();
+    drop(config);
+
+    let birthday = (username == owner).then_some(crate::BIRTHDAY);
+    match crate::render::build_model(
+        client,
+        username,
+        birthday,
+        show_collaborators,
+        show_star_history,
+        show_spotlight,
+        show_contribution_history,
+        show_top_languages,
+        show_commits_all_time,
+        after_hours.as_ref(),
+        streak.as_ref(),
+        &pipeline,
+        Some(&quote_config),
+        Some(&weather_config),
+        &status_entries,
+        &timeline,
+        &skills,
+        &maintained_repos,
+        loc_commit_cap,
+        skip_loc,
+        Some(&custom_command),
+        enable_plugins,
+    ) {
+        Ok(mut model) => {
+            model.options.palette = palette;
+            model.options.stat_limits = stat_limits;
+            model.options.private_contributions = private_contributions;
+            model.options.locale = locale;
+            model.options.custom_theme = custom_theme;
+            model.options.ascii_art = ascii_art;
+            model.options.avatar = show_avatar.then(|| client.avatar_url(username).and_then(|url| crate::avatar::fetch_base64(&url))).and_then(|result| match result {
+                Ok(data_uri) => Some(data_uri),
+                Err(e) => {
+                    eprintln!("warning: show_avatar failed ({e}), using ASCII art instead");
+                    None
+                }
+            });
+            model.stats.profile_views = Some(visitor_counter.record_visit(username));
+            for warning in crate::svg::validate_row_widths(&model.stats, model.age.as_deref(), &model.options) {
+                eprintln!("warning: {warning}");
+            }
+            Response::svg(SvgRenderer.render_cached(&model, &theme, username, fragment_cache).remove(0).content)
+        }
+        Err(e) => Response::json("502 Bad Gateway", serde_json::json!({ "error": e.to_string() })),
+    }
+}
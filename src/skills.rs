@@ -0,0 +1,49 @@
+//! Config-driven "Skills" section: each configured skill gets a small
+//! horizontal progress bar showing its level, rendered via `svg.rs`'s
+//! shared [`crate::svg`] component layout system.
+
+use serde::Deserialize;
+
+/// One skill and its level, as configured under `Config::skills`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SkillEntry {
+    pub name: String,
+    /// 0-100; clamped when rendered so a bad config value can't overflow the bar.
+    pub level: u8,
+}
+
+const BAR_ROW_HEIGHT: u32 = 22;
+const BAR_WIDTH: u32 = 100;
+const BAR_HEIGHT: u32 = 8;
+const BAR_LABEL_WIDTH: u32 = 90;
+
+/// Vertical space `entries` will occupy when rendered, `0` if empty (so
+/// `CardComponent::height`'s "not shown" convention holds without a
+/// separate `is_empty` check at call sites).
+pub fn height(entries: &[SkillEntry]) -> u32 {
+    if entries.is_empty() { 0 } else { entries.len() as u32 * BAR_ROW_HEIGHT }
+}
+
+/// Renders `entries` as a name label plus a horizontal progress bar per
+/// skill, stacked downward from `(x, y)`. `text_attr` is a ready-made
+/// `class="..."` or `style="..."` attribute, matching
+/// [`crate::svg::render_legend`]'s convention; `fill_color` is the bar's
+/// themed accent color, resolved by the caller so this module doesn't need
+/// to know about themes.
+pub fn render_skills(entries: &[SkillEntry], x: u32, y: u32, text_attr: &str, fill_color: &str) -> String {
+    entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let row_y = y + i as u32 * BAR_ROW_HEIGHT;
+            let bar_x = x + BAR_LABEL_WIDTH;
+            let bar_y = row_y.saturating_sub(BAR_HEIGHT);
+            let filled_width = BAR_WIDTH * entry.level.min(100) as u32 / 100;
+            format!(
+                r#"<text x="{x}" y="{row_y}" {text_attr}>{}</text><rect x="{bar_x}" y="{bar_y}" width="{BAR_WIDTH}" height="{BAR_HEIGHT}" fill="none" stroke="{fill_color}"/><rect x="{bar_x}" y="{bar_y}" width="{filled_width}" height="{BAR_HEIGHT}" fill="{fill_color}"/>"#,
+                entry.name,
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n    ")
+}
@@ -0,0 +1,55 @@
+//! Optional "Spotlight" box: a bordered mini-card inside the main card
+//! highlighting the user's most-starred repo, fetched via
+//! [`crate::github::GithubClient::spotlight_repo`]. Data-derived and
+//! opt-in like [`crate::star_history`], not static configuration.
+
+use crate::github::SpotlightRepo;
+
+const BOX_HEIGHT: u32 = 70;
+const BOX_WIDTH: u32 = 260;
+const LANGUAGE_DOT_RADIUS: u32 = 4;
+
+/// `0` (and thus "not shown") when `repo` is `None`, e.g. the user owns no
+/// repositories.
+pub fn height(repo: &Option<SpotlightRepo>) -> u32 {
+    if repo.is_some() {
+        BOX_HEIGHT
+    } else {
+        0
+    }
+}
+
+pub fn render_spotlight(repo: &Option<SpotlightRepo>, x: u32, y: u32, text_attr: &str) -> String {
+    let Some(repo) = repo else {
+        return String::new();
+    };
+
+    let outline = format!(r#"<rect x="{x}" y="{y}" width="{BOX_WIDTH}" height="{BOX_HEIGHT}" rx="4.5" fill="none" stroke="currentColor"/>"#);
+    let name_y = y + 20;
+    let name = format!(r#"<text x="{}" y="{name_y}" {text_attr}>{}</text>"#, x + 10, crate::ascii::escape_xml_text(&repo.name));
+
+    let description_y = y + 40;
+    let description = match &repo.description {
+        Some(text) => format!(r#"<text x="{}" y="{description_y}" {text_attr}>{}</text>"#, x + 10, crate::ascii::escape_xml_text(text)),
+        None => String::new(),
+    };
+
+    let footer_y = y + 60;
+    let language_markup = match &repo.language {
+        Some(language) => {
+            let color = crate::linguist::color_for(language);
+            let dot_x = x + 10 + LANGUAGE_DOT_RADIUS;
+            let dot_y = footer_y - LANGUAGE_DOT_RADIUS;
+            format!(
+                r#"<circle cx="{dot_x}" cy="{dot_y}" r="{LANGUAGE_DOT_RADIUS}" fill="{color}"/><text x="{}" y="{footer_y}" {text_attr}>{}</text>"#,
+                x + 10 + LANGUAGE_DOT_RADIUS * 2 + 4,
+                crate::ascii::escape_xml_text(language)
+            )
+        }
+        None => String::new(),
+    };
+    let stars_x = x + BOX_WIDTH - 60;
+    let stars = format!(r#"<text x="{stars_x}" y="{footer_y}" {text_attr}>★ {}</text>"#, repo.stars);
+
+    format!("{outline}\n    {name}\n    {description}\n    {language_markup}\n    {stars}")
+}
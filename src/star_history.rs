@@ -0,0 +1,40 @@
+//! "Stars over time" line chart: a small cumulative sparkline over the
+//! points from [`crate::github::GithubClient::star_history`]. Opt-in, and
+//! data-derived like [`crate::contribution_mix`], since it comes from live
+//! stargazer history rather than static configuration.
+
+use crate::github::StarHistoryPoint;
+
+const CHART_HEIGHT: u32 = 40;
+const CHART_WIDTH: u32 = 200;
+
+/// `0` (and thus "not shown") with fewer than two points, since a single
+/// point has no line to draw.
+pub fn height(points: &[StarHistoryPoint]) -> u32 {
+    if points.len() < 2 {
+        0
+    } else {
+        CHART_HEIGHT
+    }
+}
+
+pub fn render_chart(points: &[StarHistoryPoint], x: u32, y: u32, stroke_color: &str) -> String {
+    if points.len() < 2 {
+        return String::new();
+    }
+
+    let max = points.last().map(|p| p.cumulative).unwrap_or(1).max(1) as f32;
+    let step = CHART_WIDTH as f32 / (points.len() - 1) as f32;
+    let coords = points
+        .iter()
+        .enumerate()
+        .map(|(i, point)| {
+            let px = x as f32 + i as f32 * step;
+            let py = y as f32 + CHART_HEIGHT as f32 - (point.cumulative as f32 / max) * CHART_HEIGHT as f32;
+            format!("{px:.1},{py:.1}")
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    format!(r#"<polyline points="{coords}" fill="none" stroke="{stroke_color}" stroke-width="2"/>"#)
+}
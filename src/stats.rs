@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+
+/// Aggregated numbers pulled from the GitHub API for a single profile card run.
+///
+/// Derives `Serialize`/`Deserialize` so it can be persisted (e.g. in future
+/// history caches) behind a versioned envelope like [`crate::github`]'s
+/// `CacheEnvelope`; new fields should get `#[serde(default)]` so old files
+/// keep loading.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Stats {
+    pub username: String,
+    pub stars: u32,
+    pub commits: u32,
+    pub repos: u32,
+    pub followers: u32,
+    pub languages: u32,
+    pub loc_add: u64,
+    pub loc_del: u64,
+    /// Whether at least one repo's LOC walk hit `Config::loc_commit_cap`
+    /// before reaching the end of its history, meaning `loc_add`/`loc_del`
+    /// undercount. `false` when uncapped. See
+    /// [`crate::github::GithubClient::repo_loc`].
+    #[serde(default)]
+    pub loc_truncated: bool,
+    /// Whether LOC collection was skipped entirely (`--skip-loc`), so
+    /// `loc_add`/`loc_del` are meaningless zeros rather than a real count of
+    /// zero. Distinct from `loc_truncated`, which means a partial count.
+    #[serde(default)]
+    pub loc_skipped: bool,
+    /// Median time-to-first-response on issues across owned repos, in hours.
+    /// `None` if the sample had no answered issues to measure. New field,
+    /// so `#[serde(default)]` keeps old cached files loading.
+    #[serde(default)]
+    pub median_issue_response_hours: Option<f64>,
+    /// Share (0.0-1.0) of commits made outside a configurable weekday work
+    /// window. `None` unless explicitly opted into, since it costs an extra
+    /// history walk per repo.
+    #[serde(default)]
+    pub after_hours_share: Option<f64>,
+    /// Length, in days, of the user's current contribution streak. `None`
+    /// unless explicitly opted into, since it costs an extra
+    /// contribution-calendar query. See [`crate::streak`].
+    #[serde(default)]
+    pub current_streak: Option<u32>,
+    /// Longest contribution streak, in days, within the last year covered
+    /// by the contribution calendar. `None` under the same conditions as
+    /// `current_streak`.
+    #[serde(default)]
+    pub longest_streak: Option<u32>,
+    /// All-time commit count, from account creation through the current
+    /// year, unlike `commits` which only covers the current year. `None`
+    /// unless explicitly opted into, since it costs one query per year. See
+    /// [`crate::github::GithubClient::commit_count_all_time`].
+    #[serde(default)]
+    pub commits_all_time: Option<u32>,
+    /// A quote picked from `Config::quote`/`--quotes-file`, if any list was
+    /// configured. See [`crate::quote`].
+    #[serde(default)]
+    pub quote: Option<String>,
+    /// Current weather for `Config::weather`'s configured city, if any. See
+    /// [`crate::weather`].
+    #[serde(default)]
+    pub weather: Option<String>,
+    /// Cumulative `/card` hit count for this user, if the server-mode
+    /// visitor counter recorded this render. See [`crate::visitors`].
+    #[serde(default)]
+    pub profile_views: Option<u64>,
+    /// The active entry from `Config::status`'s date-ranged list, if any is
+    /// in effect today. See [`crate::status`].
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Comma-separated names of the most recently pushed-to owned repos,
+    /// most recent first, for the "Now hacking on" row. `None` if the user
+    /// owns no repositories. See
+    /// [`crate::github::GithubClient::currently_working_on`].
+    #[serde(default)]
+    pub now_hacking_on: Option<String>,
+    /// `"<own> own, <org> org, <other> other"` breakdown of commit
+    /// contributions by repository owner type, for the "Commits by owner"
+    /// row. `None` if the user made no commit contributions to bucket. See
+    /// [`crate::github::GithubClient::commits_by_owner_type`].
+    #[serde(default)]
+    pub commits_by_owner: Option<String>,
+    /// `"<label>: <value>"` for the "Custom stat" row, from
+    /// `Config::custom_stat`'s formula evaluated by
+    /// [`crate::postprocess::FormulaStat`]. `None` unless configured, or if
+    /// the formula fails to evaluate (unknown field, divide by zero).
+    #[serde(default)]
+    pub custom_stat: Option<String>,
+}
+
+impl Stats {
+    pub fn loc_total(&self) -> i64 {
+        self.loc_add as i64 - self.loc_del as i64
+    }
+}
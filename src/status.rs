@@ -0,0 +1,38 @@
+//! Config-driven "status" row (e.g. "Open to work from 2025-06-01",
+//! "Currently hiring for X") that appears only while today falls inside its
+//! configured date range, so a status doesn't need to be manually toggled
+//! on and off as plans change.
+
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+/// One status message and the date range it's shown for, as configured
+/// under `Config::status`. Dates are `"YYYY-MM-DD"` strings, parsed the same
+/// way `render::build_model` parses a birthday; either bound missing leaves
+/// that side of the range open-ended.
+#[derive(Debug, Clone, Deserialize)]
+pub struct StatusEntry {
+    pub message: String,
+    #[serde(default)]
+    pub from: Option<String>,
+    #[serde(default)]
+    pub until: Option<String>,
+}
+
+impl StatusEntry {
+    fn active_on(&self, today: NaiveDate) -> bool {
+        let after_from = !self.from.as_deref().and_then(parse_date).is_some_and(|from| today < from);
+        let before_until = !self.until.as_deref().and_then(parse_date).is_some_and(|until| today > until);
+        after_from && before_until
+    }
+}
+
+fn parse_date(s: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()
+}
+
+/// The first of `entries` (in configured order) active on `today`, or `None`
+/// if every entry's window has closed or hasn't opened yet.
+pub fn active(entries: &[StatusEntry], today: NaiveDate) -> Option<&str> {
+    entries.iter().find(|entry| entry.active_on(today)).map(|entry| entry.message.as_str())
+}
@@ -0,0 +1,153 @@
+//! Turns a day-by-day contribution calendar into a streak count, with
+//! configurable grace rules so someone outside UTC or who takes weekends
+//! off doesn't see a "broken" streak that naive UTC-midnight bucketing
+//! would report.
+
+use crate::github::ContributionDay;
+use chrono::{Datelike, Weekday};
+use serde::Deserialize;
+use std::collections::HashSet;
+
+/// Grace rules applied when computing a streak from a contribution calendar.
+/// Lives in the config file (see `config.rs`) since the right values depend
+/// on where the user actually lives and works, not on anything halfguru can
+/// detect on its own. Day-boundary correction itself lives in
+/// [`Config::utc_offset_hours`](crate::config::Config::utc_offset_hours),
+/// shared with every other date-bucketed stat rather than duplicated here.
+#[derive(Debug, Clone, Deserialize)]
+pub struct StreakConfig {
+    /// Whether issues, PRs, and reviews count toward a day being "active",
+    /// not just commits. GitHub's calendar always combines all four
+    /// already, with no way to ask for commits alone — this exists for
+    /// forward compatibility with a commits-only calendar, and setting it
+    /// to `false` today has no effect.
+    #[serde(default = "default_true")]
+    pub count_issues_and_prs: bool,
+    /// A gap on Saturday or Sunday doesn't break the streak — it's skipped
+    /// over instead of resetting the counter.
+    #[serde(default)]
+    pub skip_weekends: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for StreakConfig {
+    fn default() -> Self {
+        Self { count_issues_and_prs: true, skip_weekends: false }
+    }
+}
+
+/// Current and longest streak lengths, in days.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Streak {
+    pub current: u32,
+    pub longest: u32,
+}
+
+/// Computes current/longest streaks from a contribution calendar. `days`
+/// need not be sorted or deduplicated. A day with `count == 0` is treated
+/// as inactive unless `skip_weekends` covers it, and so is any date missing
+/// from `days` between its earliest and latest date. `utc_offset_hours` comes
+/// from [`crate::config::Config::utc_offset_hours`], shared with every other
+/// date-bucketed stat.
+pub fn compute_streak(days: &[ContributionDay], utc_offset_hours: i32, config: &StreakConfig) -> Streak {
+    let shifted: Vec<ContributionDay> = days
+        .iter()
+        .map(|day| ContributionDay { date: crate::datebucket::bucket_day(day.date, utc_offset_hours), count: day.count })
+        .collect();
+
+    let active: HashSet<chrono::NaiveDate> = shifted.iter().filter(|d| d.count > 0).map(|d| d.date).collect();
+    let mut sorted: Vec<chrono::NaiveDate> = shifted.iter().map(|d| d.date).collect();
+    sorted.sort();
+    sorted.dedup();
+    let sorted: Vec<chrono::NaiveDate> = match (sorted.first(), sorted.last()) {
+        (Some(&first), Some(&last)) => first.iter_days().take_while(|date| *date <= last).collect(),
+        _ => Vec::new(),
+    };
+
+    let is_gap_day = |date: &chrono::NaiveDate| config.skip_weekends && matches!(date.weekday(), Weekday::Sat | Weekday::Sun);
+
+    let mut longest = 0u32;
+    let mut run = 0u32;
+    for date in &sorted {
+        if active.contains(date) {
+            run += 1;
+        } else if is_gap_day(date) {
+            // weekend gap: streak continues without extending it
+        } else {
+            longest = longest.max(run);
+            run = 0;
+        }
+    }
+    longest = longest.max(run);
+
+    let mut current = 0u32;
+    for date in sorted.iter().rev() {
+        if active.contains(date) {
+            current += 1;
+        } else if is_gap_day(date) {
+            continue;
+        } else {
+            break;
+        }
+    }
+
+    Streak { current, longest }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn day(y: i32, m: u32, d: u32, count: u32) -> ContributionDay {
+        ContributionDay { date: NaiveDate::from_ymd_opt(y, m, d).unwrap(), count }
+    }
+
+    #[test]
+    fn simple_run_ending_today() {
+        let days = vec![day(2024, 1, 1, 1), day(2024, 1, 2, 1), day(2024, 1, 3, 1)];
+        let streak = compute_streak(&days, 0, &StreakConfig::default());
+        assert_eq!(streak.current, 3);
+        assert_eq!(streak.longest, 3);
+    }
+
+    #[test]
+    fn gap_breaks_streak_by_default() {
+        // Jan 4 (Thursday) has no contributions and isn't a weekend, so the
+        // run before it shouldn't count toward the current streak.
+        let days = vec![day(2024, 1, 1, 1), day(2024, 1, 2, 1), day(2024, 1, 3, 1), day(2024, 1, 5, 1)];
+        let streak = compute_streak(&days, 0, &StreakConfig::default());
+        assert_eq!(streak.current, 1);
+        assert_eq!(streak.longest, 3);
+    }
+
+    #[test]
+    fn skip_weekends_bridges_a_saturday_sunday_gap() {
+        // 2024-01-05 is a Friday, 2024-01-06/07 are Sat/Sun with no activity,
+        // 2024-01-08 is a Monday with activity again.
+        let config = StreakConfig { skip_weekends: true, ..StreakConfig::default() };
+        let days = vec![day(2024, 1, 5, 1), day(2024, 1, 8, 1)];
+        let streak = compute_streak(&days, 0, &config);
+        assert_eq!(streak.current, 2);
+        assert_eq!(streak.longest, 2);
+    }
+
+    #[test]
+    fn weekend_gap_without_skip_weekends_still_breaks_streak() {
+        let days = vec![day(2024, 1, 5, 1), day(2024, 1, 8, 1)];
+        let streak = compute_streak(&days, 0, &StreakConfig::default());
+        assert_eq!(streak.current, 1);
+        assert_eq!(streak.longest, 1);
+    }
+
+    #[test]
+    fn no_active_days_gives_zero_streak() {
+        let days = vec![day(2024, 1, 1, 0), day(2024, 1, 2, 0)];
+        let streak = compute_streak(&days, 0, &StreakConfig::default());
+        assert_eq!(streak.current, 0);
+        assert_eq!(streak.longest, 0);
+    }
+}
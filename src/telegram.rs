@@ -0,0 +1,44 @@
+//! Posts a run summary to a Telegram chat via the Bot API — another
+//! [`crate::webhook::Notifier`] alongside Discord/Slack, for users who'd
+//! rather get a Telegram message than watch a webhook channel.
+//!
+//! Unlike the Discord/Slack webhooks, there's no single URL to configure:
+//! a bot's token and the target chat id are two separate secrets
+//! (`TELEGRAM_BOT_TOKEN`/`TELEGRAM_CHAT_ID`), resolved through
+//! [`crate::secrets`] like every other credential this crate needs.
+
+use crate::error::Result;
+use crate::stats::Stats;
+use crate::webhook::{summarize, Notifier};
+
+const API_BASE: &str = "https://api.telegram.org";
+
+/// Posts to a Telegram chat via `bot_token`'s Bot API, attaching `image` as
+/// a photo when given.
+pub struct TelegramNotifier {
+    pub bot_token: String,
+    pub chat_id: String,
+}
+
+impl Notifier for TelegramNotifier {
+    fn notify(&self, stats: &Stats, image: Option<&[u8]>) -> Result<()> {
+        let client = reqwest::blocking::Client::new();
+        let text = summarize(stats);
+        match image {
+            Some(image) => {
+                let part = reqwest::blocking::multipart::Part::bytes(image.to_vec()).file_name("card.png").mime_str("image/png")?;
+                let form = reqwest::blocking::multipart::Form::new()
+                    .text("chat_id", self.chat_id.clone())
+                    .text("caption", text)
+                    .text("parse_mode", "Markdown")
+                    .part("photo", part);
+                client.post(format!("{API_BASE}/bot{}/sendPhoto", self.bot_token)).multipart(form).send()?.error_for_status()?;
+            }
+            None => {
+                let payload = serde_json::json!({ "chat_id": self.chat_id, "text": text, "parse_mode": "Markdown" });
+                client.post(format!("{API_BASE}/bot{}/sendMessage", self.bot_token)).json(&payload).send()?.error_for_status()?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+//! Optional OpenTelemetry span export. Off by default so a plain CLI run
+//! pays no cost; pass `--otlp-endpoint` (or set `OTEL_EXPORTER_OTLP_ENDPOINT`)
+//! to have spans around GraphQL calls, LOC aggregation, and rendering
+//! shipped to a collector, so a slow daemon/server run can be diagnosed
+//! instead of just timed with `eprintln!`.
+
+use opentelemetry::global;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+/// Installs a global tracing subscriber. With `otlp_endpoint` set, spans are
+/// exported over OTLP; otherwise this is a no-op and `tracing::instrument`ed
+/// functions run with no active subscriber attached.
+pub fn init(otlp_endpoint: Option<&str>) {
+    let Some(endpoint) = otlp_endpoint else {
+        return;
+    };
+
+    let tracer = match opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(opentelemetry_otlp::new_exporter().tonic().with_endpoint(endpoint))
+        .install_simple()
+    {
+        Ok(tracer) => tracer,
+        Err(e) => {
+            eprintln!("otlp tracing disabled, failed to initialize exporter: {e}");
+            return;
+        }
+    };
+
+    let telemetry_layer = tracing_opentelemetry::layer().with_tracer(tracer);
+    if tracing_subscriber::registry().with(telemetry_layer).try_init().is_err() {
+        eprintln!("otlp tracing disabled, a global subscriber was already installed");
+    }
+}
+
+/// Flushes any buffered spans. Should run before the process exits so the
+/// last batch of a one-shot CLI run isn't dropped on the floor.
+pub fn shutdown() {
+    global::shutdown_tracer_provider();
+}
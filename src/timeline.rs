@@ -0,0 +1,37 @@
+//! Config-driven "mini-CV" section: a short vertical list of `year → role`
+//! entries, for users who want a career timeline alongside their stats.
+
+use serde::Deserialize;
+
+/// One entry in a career timeline, e.g. `{ "year": "2023", "role": "Staff
+/// Engineer @ Acme" }`, as configured under `Config::timeline`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TimelineEntry {
+    pub year: String,
+    pub role: String,
+}
+
+const TIMELINE_ROW_HEIGHT: u32 = 22;
+
+/// Vertical space `entries` will occupy when rendered, `0` if empty (so
+/// `CardComponent::height`'s "not shown" convention holds without a
+/// separate `is_empty` check at call sites).
+pub fn height(entries: &[TimelineEntry]) -> u32 {
+    if entries.is_empty() { 0 } else { entries.len() as u32 * TIMELINE_ROW_HEIGHT }
+}
+
+/// Renders `entries` as one `year — role` line per entry, stacked downward
+/// from `(x, y)`. `text_attr` is a ready-made `class="..."` or `style="..."`
+/// attribute, matching [`crate::svg::render_legend`]'s convention so this
+/// module doesn't need to know about themes.
+pub fn render_timeline(entries: &[TimelineEntry], x: u32, y: u32, text_attr: &str) -> String {
+    entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let row_y = y + i as u32 * TIMELINE_ROW_HEIGHT;
+            format!(r#"<text x="{x}" y="{row_y}" {text_attr}>{} — {}</text>"#, entry.year, entry.role)
+        })
+        .collect::<Vec<_>>()
+        .join("\n    ")
+}
@@ -0,0 +1,44 @@
+//! "Top Languages" section: a legend of the languages behind
+//! [`crate::github::GithubClient::language_totals`]'s byte counts across
+//! owned repos, by share of the total — the language analogue of
+//! [`crate::contribution_mix`], reusing the same [`crate::svg::render_legend`]
+//! plumbing but over a variable-length, sorted-by-size list instead of four
+//! fixed categories.
+
+use crate::svg::LegendEntry;
+
+const LEGEND_MAX_PER_ROW: usize = 2;
+
+/// Languages shown in the legend, largest first. An honest cap, like the
+/// page limits in `github.rs` — someone who writes a dozen languages sees
+/// their five biggest rather than a legend that dwarfs the rest of the card.
+const TOP_LANGUAGES_LIMIT: usize = 5;
+
+/// Sorts `totals` by byte count descending and keeps the top
+/// [`TOP_LANGUAGES_LIMIT`], dropping anything with zero bytes.
+fn top(totals: &[(String, u64)]) -> Vec<(&str, u64)> {
+    let mut sorted: Vec<(&str, u64)> = totals.iter().filter(|(_, size)| *size > 0).map(|(name, size)| (name.as_str(), *size)).collect();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1));
+    sorted.truncate(TOP_LANGUAGES_LIMIT);
+    sorted
+}
+
+/// `0` (and thus "not shown") when `totals` has no languages at all, e.g. an
+/// account with no repos, or every repo excluded as a fork.
+pub fn height(totals: &[(String, u64)]) -> u32 {
+    let top = top(totals);
+    if top.is_empty() { 0 } else { crate::svg::legend_height(top.len(), LEGEND_MAX_PER_ROW) }
+}
+
+pub fn render_languages(totals: &[(String, u64)], x: u32, y: u32, text_attr: &str) -> String {
+    let top = top(totals);
+    if top.is_empty() {
+        return String::new();
+    }
+    let total: u64 = top.iter().map(|(_, size)| size).sum();
+    let entries: Vec<LegendEntry> = top
+        .into_iter()
+        .map(|(name, size)| LegendEntry { label: name.to_string(), color: crate::linguist::color_for(name), share: size as f64 / total as f64 })
+        .collect();
+    crate::svg::render_legend(&entries, x, y, LEGEND_MAX_PER_ROW, text_attr)
+}
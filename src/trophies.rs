@@ -0,0 +1,79 @@
+//! Achievements computed locally from already-fetched [`Stats`], rendered as a
+//! row of small medallions (see github-profile-trophy for the inspiration).
+
+use crate::stats::Stats;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Tier {
+    Bronze,
+    Silver,
+    Gold,
+}
+
+impl Tier {
+    fn color(self) -> &'static str {
+        match self {
+            Tier::Bronze => "#c47a3b",
+            Tier::Silver => "#b0b7bd",
+            Tier::Gold => "#e6b800",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Trophy {
+    pub label: &'static str,
+    pub tier: Tier,
+}
+
+/// Computes the trophies a profile has earned. Purely local — no extra API calls.
+pub fn compute_trophies(stats: &Stats) -> Vec<Trophy> {
+    const STAR_THRESHOLDS: [(u32, Tier); 3] = [(100, Tier::Bronze), (500, Tier::Silver), (1000, Tier::Gold)];
+    const COMMIT_THRESHOLDS: [(u32, Tier); 3] = [(100, Tier::Bronze), (500, Tier::Silver), (1000, Tier::Gold)];
+    const FOLLOWER_THRESHOLDS: [(u32, Tier); 3] = [(10, Tier::Bronze), (50, Tier::Silver), (100, Tier::Gold)];
+    const LANGUAGE_THRESHOLDS: [(u32, Tier); 3] = [(3, Tier::Bronze), (6, Tier::Silver), (10, Tier::Gold)];
+
+    let mut trophies = Vec::new();
+    if let Some(tier) = tier_for(stats.stars, &STAR_THRESHOLDS) {
+        trophies.push(Trophy { label: "Stars", tier });
+    }
+    if let Some(tier) = tier_for(stats.commits, &COMMIT_THRESHOLDS) {
+        trophies.push(Trophy { label: "Commits", tier });
+    }
+    if let Some(tier) = tier_for(stats.followers, &FOLLOWER_THRESHOLDS) {
+        trophies.push(Trophy { label: "Followers", tier });
+    }
+    if let Some(tier) = tier_for(stats.languages, &LANGUAGE_THRESHOLDS) {
+        trophies.push(Trophy { label: "Polyglot", tier });
+    }
+    trophies
+}
+
+fn tier_for(value: u32, thresholds: &[(u32, Tier)]) -> Option<Tier> {
+    thresholds.iter().rev().find(|(min, _)| value >= *min).map(|(_, tier)| tier).copied()
+}
+
+const MEDALLION_SIZE: u32 = 28;
+const MEDALLION_GAP: u32 = 10;
+
+/// Renders `trophies` as a row of small iconified medallions starting at
+/// `(x, y)`. `label_attr` is the pre-resolved `class="..."` or `style="..."`
+/// for the label text, so this module doesn't need to know about themes.
+pub fn render_trophies(trophies: &[Trophy], x: u32, y: u32, label_attr: &str) -> String {
+    trophies
+        .iter()
+        .enumerate()
+        .map(|(i, trophy)| {
+            let cx = x + i as u32 * (MEDALLION_SIZE + MEDALLION_GAP) + MEDALLION_SIZE / 2;
+            format!(
+                r#"<g class="trophy"><circle cx="{cx}" cy="{y}" r="{r}" fill="{color}"/><text x="{cx}" y="{ty}" {label_attr} text-anchor="middle">{label}</text></g>"#,
+                r = MEDALLION_SIZE / 2,
+                color = trophy.tier.color(),
+                ty = y + MEDALLION_SIZE,
+                label = trophy.label,
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n    ")
+}
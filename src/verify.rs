@@ -0,0 +1,70 @@
+//! `halfguru verify`: recomputes stats that have two independent paths
+//! through the API and reports any disagreement, so a pagination or
+//! attribution bug turns into a loud discrepancy here instead of a quietly
+//! wrong number on someone's profile card.
+
+use crate::github::GithubClient;
+
+struct Check {
+    name: &'static str,
+    primary: String,
+    secondary: String,
+    agree: bool,
+}
+
+fn check(name: &'static str, primary: impl ToString, secondary: impl ToString) -> Check {
+    let primary = primary.to_string();
+    let secondary = secondary.to_string();
+    let agree = primary == secondary;
+    Check { name, primary, secondary, agree }
+}
+
+/// Runs every cross-check for `username` and prints the results. Returns
+/// `true` if every check agreed.
+pub fn run(client: &GithubClient, username: &str) -> Result<bool, crate::error::Error> {
+    let checks = vec![check_repo_count(client, username)?, check_loc(client, username)?];
+
+    let mut all_agree = true;
+    for check in &checks {
+        all_agree &= check.agree;
+        let status = if check.agree { "OK" } else { "MISMATCH" };
+        println!("[{status}] {}: {} vs {}", check.name, check.primary, check.secondary);
+    }
+    Ok(all_agree)
+}
+
+/// `totalCount` on the owned-repos query vs the length of the fully paged
+/// node list — would disagree if a user owns more repos than
+/// [`GithubClient::list_owned_repos`]'s page limit (100 repos a page) reaches.
+fn check_repo_count(client: &GithubClient, username: &str) -> Result<Check, crate::error::Error> {
+    let via_total_count = client.repo_count(username)?;
+    let via_nodes = client.list_owned_repos(username)?.len();
+    Ok(check("repo count (totalCount vs nodes)", via_total_count, via_nodes))
+}
+
+/// GraphQL commit-history LOC vs the REST contributor-stats endpoint's own
+/// per-author tally, summed across every owned repository. The two use
+/// unrelated computations on GitHub's end, so a mismatch usually means one
+/// side is missing pages, not that a real discrepancy exists in the data.
+fn check_loc(client: &GithubClient, username: &str) -> Result<Check, crate::error::Error> {
+    // Uncapped, unlike the render pipeline's `Config::loc_commit_cap`, since a
+    // capped comparison would flag every large repo as a false mismatch.
+    let (graphql_add, graphql_del, _truncated) = client.total_loc(username, None)?;
+
+    let mut rest_add = 0u64;
+    let mut rest_del = 0u64;
+    let mut pending = false;
+    for repo in client.list_owned_repos(username)? {
+        match client.repo_contributor_loc(username, &repo.name, username)? {
+            Some((add, del)) => {
+                rest_add += add;
+                rest_del += del;
+            }
+            None => pending = true,
+        }
+    }
+
+    let agree = !pending && rest_add == graphql_add && rest_del == graphql_del;
+    let secondary = format!("+{rest_add}/-{rest_del}{}", if pending { " (some repos still computing, treated as a mismatch)" } else { "" });
+    Ok(Check { name: "total LOC (GraphQL history vs REST contributor stats)", primary: format!("+{graphql_add}/-{graphql_del}"), secondary, agree })
+}
@@ -0,0 +1,44 @@
+//! Self-hosted stand-in for third-party visitor badge services (e.g.
+//! komarev's): a per-username hit counter persisted to disk, incremented
+//! once per `/card` request in server mode (see `server.rs`). Not wired
+//! into one-shot CLI renders, since a scheduled `cargo run`/cron render
+//! isn't a "visit" — there's no viewer behind it.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+const COUNTER_FILE: &str = "visitors.json";
+
+/// Loaded once at server startup and shared across every request-handling
+/// thread, the same way `FragmentCache` is (see `svg.rs`).
+pub struct VisitorCounter {
+    path: PathBuf,
+    counts: Mutex<HashMap<String, u64>>,
+}
+
+impl VisitorCounter {
+    /// Loads existing counts from `<cache_dir>/visitors.json`, starting
+    /// fresh if the file is missing or unreadable.
+    pub fn load(cache_dir: &Path) -> Self {
+        let path = cache_dir.join(COUNTER_FILE);
+        let counts = std::fs::read_to_string(&path).ok().and_then(|raw| serde_json::from_str(&raw).ok()).unwrap_or_default();
+        Self { path, counts: Mutex::new(counts) }
+    }
+
+    /// Increments `username`'s count and returns the new total, persisting
+    /// the whole map to disk on every visit. Hit counters are read far more
+    /// often than written, so re-writing the small JSON file each time keeps
+    /// this to one on-disk format instead of an append log plus compaction.
+    pub fn record_visit(&self, username: &str) -> u64 {
+        let mut counts = self.counts.lock().expect("visitor counter lock poisoned");
+        let count = counts.entry(username.to_string()).or_insert(0);
+        *count += 1;
+        let total = *count;
+        if let Ok(json) = serde_json::to_string_pretty(&*counts) {
+            let _ = std::fs::create_dir_all(self.path.parent().unwrap_or(Path::new(".")));
+            let _ = std::fs::write(&self.path, json);
+        }
+        total
+    }
+}
@@ -0,0 +1,71 @@
+//! Fetches current weather for a configured city from open-meteo (no API
+//! key required) for the optional "Weather" row — a popular dynamic-README
+//! gimmick. Unlike `github.rs`'s collectors this talks to an unrelated,
+//! unauthenticated API, so a failure here is swallowed rather than
+//! propagated: a down third-party weather service shouldn't take the whole
+//! card render down with it.
+
+use serde::Deserialize;
+
+const FORECAST_ENDPOINT: &str = "https://api.open-meteo.com/v1/forecast";
+
+/// Coordinates for the "Weather" row, as configured under `Config::weather`.
+/// Either being `None` leaves the row disabled rather than falling back to
+/// some arbitrary default city.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct WeatherConfig {
+    #[serde(default)]
+    pub latitude: Option<f64>,
+    #[serde(default)]
+    pub longitude: Option<f64>,
+}
+
+impl WeatherConfig {
+    fn coordinates(&self) -> Option<(f64, f64)> {
+        Some((self.latitude?, self.longitude?))
+    }
+}
+
+#[derive(Deserialize)]
+struct ForecastResponse {
+    current_weather: CurrentWeather,
+}
+
+#[derive(Deserialize)]
+struct CurrentWeather {
+    temperature: f64,
+    weathercode: u32,
+}
+
+/// Fetches `"<temp>°C, <condition> <emoji>"` for `config`'s coordinates, or
+/// `None` if no city is configured or the request fails for any reason.
+pub fn fetch(config: &WeatherConfig) -> Option<String> {
+    let (latitude, longitude) = config.coordinates()?;
+    let response = reqwest::blocking::Client::new()
+        .get(FORECAST_ENDPOINT)
+        .query(&[("latitude", latitude), ("longitude", longitude)])
+        .query(&[("current_weather", "true")])
+        .send()
+        .ok()?
+        .error_for_status()
+        .ok()?
+        .json::<ForecastResponse>()
+        .ok()?;
+    let (description, emoji) = describe(response.current_weather.weathercode);
+    Some(format!("{:.0}°C, {description} {emoji}", response.current_weather.temperature))
+}
+
+/// Condensed from the WMO weather interpretation codes open-meteo uses (see
+/// https://open-meteo.com/en/docs) into the handful of buckets this row
+/// bothers to show distinctly.
+fn describe(code: u32) -> (&'static str, &'static str) {
+    match code {
+        0 => ("clear", "☀️"),
+        1..=3 => ("cloudy", "⛅"),
+        45 | 48 => ("fog", "🌫"),
+        51..=67 | 80..=82 => ("rain", "🌧"),
+        71..=77 | 85 | 86 => ("snow", "❄"),
+        95..=99 => ("storm", "⛈"),
+        _ => ("unknown", "❓"),
+    }
+}
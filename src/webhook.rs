@@ -0,0 +1,84 @@
+//! Posts a compact run summary to a configured Discord or Slack incoming
+//! webhook after each render — handy for `halfguru server` running
+//! unattended on a home server, where nobody's watching stdout to notice a
+//! stat finally crossed some milestone.
+//!
+//! [`Notifier`] is the shared shape every post-run notification channel
+//! implements — Discord and Slack here, plus [`crate::telegram`]. `main.rs`
+//! picks and constructs whichever ones are configured and calls each one's
+//! [`Notifier::notify`] independently, so a run can notify several channels
+//! at once without one failing the others.
+//!
+//! The Discord/Slack split is detected from the URL rather than a separate
+//! flag: `discord.com`/`discordapp.com` webhooks get Discord's `content`
+//! field and, if asked, the rendered PNG attached as a multipart file;
+//! anything else is treated as a Slack incoming webhook, which takes a plain
+//! `text` field and — unlike Discord's webhook endpoint — has no way to
+//! attach a file, so an image is silently dropped there rather than failing
+//! the whole notification.
+
+use crate::error::Result;
+use crate::stats::Stats;
+
+/// A channel a run summary can be posted to after a render. `image`, when
+/// given, is a rendered PNG — implementations that can't attach a file (e.g.
+/// [`SlackNotifier`]) just ignore it rather than failing.
+pub trait Notifier {
+    fn notify(&self, stats: &Stats, image: Option<&[u8]>) -> Result<()>;
+}
+
+fn is_discord(url: &str) -> bool {
+    url.contains("discord.com") || url.contains("discordapp.com")
+}
+
+/// Posts to a Discord incoming webhook URL.
+pub struct DiscordNotifier {
+    pub url: String,
+}
+
+impl Notifier for DiscordNotifier {
+    fn notify(&self, stats: &Stats, image: Option<&[u8]>) -> Result<()> {
+        let client = reqwest::blocking::Client::new();
+        let payload = serde_json::json!({ "content": summarize(stats) });
+        let request = match image {
+            Some(image) => {
+                let part = reqwest::blocking::multipart::Part::bytes(image.to_vec()).file_name("card.png").mime_str("image/png")?;
+                let form = reqwest::blocking::multipart::Form::new().text("payload_json", payload.to_string()).part("file", part);
+                client.post(&self.url).multipart(form)
+            }
+            None => client.post(&self.url).json(&payload),
+        };
+        request.send()?.error_for_status()?;
+        Ok(())
+    }
+}
+
+/// Posts to a Slack incoming webhook URL. Can't attach a file, so `image` is
+/// ignored.
+pub struct SlackNotifier {
+    pub url: String,
+}
+
+impl Notifier for SlackNotifier {
+    fn notify(&self, stats: &Stats, _image: Option<&[u8]>) -> Result<()> {
+        reqwest::blocking::Client::new().post(&self.url).json(&serde_json::json!({ "text": summarize(stats) })).send()?.error_for_status()?;
+        Ok(())
+    }
+}
+
+/// Posts a summary of `stats` to `url`, picking [`DiscordNotifier`] or
+/// [`SlackNotifier`] by URL — see the module docs.
+pub fn notify(url: &str, stats: &Stats, image: Option<&[u8]>) -> Result<()> {
+    if is_discord(url) {
+        DiscordNotifier { url: url.to_string() }.notify(stats, image)
+    } else {
+        SlackNotifier { url: url.to_string() }.notify(stats, image)
+    }
+}
+
+pub(crate) fn summarize(stats: &Stats) -> String {
+    format!(
+        "**{}**'s halfguru card refreshed — {} stars, {} commits, {} repos, {} followers",
+        stats.username, stats.stars, stats.commits, stats.repos, stats.followers
+    )
+}